@@ -1,6 +1,7 @@
 #[macro_use]
 extern crate criterion;
 extern crate blackjack;
+extern crate stats;
 
 use blackjack::prelude::*;
 use criterion::Criterion;
@@ -32,11 +33,65 @@ fn criterion_bechmark(c: &mut Criterion) {
         inputs.clone(),
     );
 
+    c.bench_function("series sum f64 10M (generic)", |b| {
+        b.iter_with_setup(
+            || Series::arange(0, 10_000_000).astype::<f64>().unwrap(),
+            |series| {
+                let _sum = series.sum();
+            },
+        )
+    });
+
+    c.bench_function("series sum f64 10M (specialized)", |b| {
+        b.iter_with_setup(
+            || Series::arange(0, 10_000_000).astype::<f64>().unwrap(),
+            |series| {
+                let _sum = series.sum_f64();
+            },
+        )
+    });
+
+    c.bench_function("series map (1k)", |b| {
+        b.iter_with_setup(
+            || Series::arange(0, 1000),
+            |series| {
+                let _res = series.map(|x| x * 2);
+            },
+        )
+    });
+
+    c.bench_function("series map_par (1k)", |b| {
+        b.iter_with_setup(
+            || Series::arange(0, 1000),
+            |series| {
+                let _res = series.map_par(|x| x * 2);
+            },
+        )
+    });
+
+    c.bench_function("series sum f64 10M (parallel)", |b| {
+        b.iter_with_setup(
+            || Series::arange(0, 10_000_000).astype::<f64>().unwrap(),
+            |series| {
+                let _sum = series.par_sum();
+            },
+        )
+    });
+
+    c.bench_function("series mean f64 10M (parallel)", |b| {
+        b.iter_with_setup(
+            || Series::arange(0, 10_000_000).astype::<f64>().unwrap(),
+            |series| {
+                let _mean = series.par_mean().unwrap();
+            },
+        )
+    });
+
     c.bench_function("series index", |b| {
         b.iter_with_setup(
             || Series::arange(0, 10000),
             |mut series| {
-                series[0] = 1.into();
+                series[0] = 1;
             },
         )
     });
@@ -125,6 +180,24 @@ fn criterion_bechmark(c: &mut Criterion) {
         )
     });
 
+    c.bench_function("series agg ops (MEDIAN 1M - select)", |b| {
+        b.iter_with_setup(
+            || Series::arange(0, 1_000_000),
+            |series| {
+                let _median = series.median().unwrap();
+            },
+        )
+    });
+
+    c.bench_function("series agg ops (MEDIAN 1M - stats)", |b| {
+        b.iter_with_setup(
+            || Series::arange(0, 1_000_000),
+            |series| {
+                let _median = stats::median(series.values.iter().map(|v| *v as f64)).unwrap();
+            },
+        )
+    });
+
     c.bench_function("series agg ops (QUANTILE)", |b| {
         b.iter_with_setup(
             || Series::arange(0, 10000),
@@ -134,6 +207,15 @@ fn criterion_bechmark(c: &mut Criterion) {
         )
     });
 
+    c.bench_function("series unique (1M, many duplicates)", |b| {
+        b.iter_with_setup(
+            || Series::from_vec((0..1_000_000).map(|v| v % 1000).collect::<Vec<i32>>()),
+            |series| {
+                let _unique = series.unique();
+            },
+        )
+    });
+
     c.bench_function("series groupby", |b| {
         b.iter_with_setup(
             || {
@@ -147,48 +229,158 @@ fn criterion_bechmark(c: &mut Criterion) {
         )
     });
 
-    c.bench_function("series rolling (MEAN)", |b| {
+    c.bench_function("series groupby (1M, 100k groups)", |b| {
         b.iter_with_setup(
             || {
-                let series = Series::arange(0, 10000);
-                series
+                let series = Series::arange(0, 1_000_000);
+                let keys = Series::from_vec((0..1_000_000).map(|v| v % 100_000).collect());
+                (keys, series)
+            },
+            |(keys, series)| {
+                let _grouped = series.groupby(&keys);
+            },
+        )
+    });
+
+    // Integer keys are grouped by value, other keys by their string form; string
+    // keys themselves can't be grouped by as they aren't numeric, so the same keys
+    // as floats stand in for them.
+    c.bench_function("series groupby (1M, 100k groups, i64 keys)", |b| {
+        b.iter_with_setup(
+            || {
+                let series = Series::arange(0, 1_000_000).astype::<i64>().unwrap();
+                let keys = Series::from_vec((0..1_000_000).map(|v| (v % 100_000) as i64).collect());
+                (keys, series)
             },
+            |(keys, series)| {
+                let _grouped = series.groupby(&keys);
+            },
+        )
+    });
+
+    c.bench_function("series groupby (1M, 100k groups, f64 keys)", |b| {
+        b.iter_with_setup(
+            || {
+                let series = Series::arange(0, 1_000_000).astype::<f64>().unwrap();
+                let keys = Series::from_vec((0..1_000_000).map(|v| (v % 100_000) as f64).collect());
+                (keys, series)
+            },
+            |(keys, series)| {
+                let _grouped = series.groupby(&keys);
+            },
+        )
+    });
+
+    c.bench_function("series groupby aggs (1M, 100k groups)", |b| {
+        b.iter_with_setup(
+            || {
+                let series = Series::arange(0, 1_000_000);
+                let keys = Series::from_vec((0..1_000_000).map(|v| v % 100_000).collect());
+                series.groupby(&keys)
+            },
+            |grouped| {
+                let _sum = grouped.sum();
+                let _mean = grouped.mean().unwrap();
+            },
+        )
+    });
+
+    c.bench_function("series rolling (MEAN)", |b| {
+        b.iter_with_setup(
+            || Series::arange(0, 10000),
             |series| {
                 let _res = series.rolling(5).mean();
             },
         )
     });
 
-    c.bench_function("series indexing (LOC)", |b| {
+    c.bench_function("series rolling (SUM 1M, window 500 - naive)", |b| {
         b.iter_with_setup(
-            || {
-                let series = Series::arange(0, 10000);
-                series
+            || Series::arange(0, 1_000_000).astype::<f64>().unwrap(),
+            |series| {
+                let _res = series
+                    .values
+                    .windows(500)
+                    .map(|window| window.iter().sum::<f64>())
+                    .collect::<Vec<f64>>();
             },
+        )
+    });
+
+    c.bench_function("series rolling (SUM 1M, window 500 - sliding)", |b| {
+        b.iter_with_setup(
+            || Series::arange(0, 1_000_000).astype::<f64>().unwrap(),
             |series| {
-                let _res = series.loc(&vec![250, 500, 1000, 2000, 4000, 5000]);
+                let _res = series.rolling(500).sum();
             },
         )
     });
 
-    c.bench_function("series indexing (ILOC)", |b| {
+    c.bench_function("series indexing (LOC)", |b| {
         b.iter_with_setup(
-            || {
-                let series = Series::arange(0, 10000);
-                series
+            || Series::arange(0, 10000),
+            |series| {
+                let _res = vec![250, 500, 1000, 2000, 4000, 5000]
+                    .into_iter()
+                    .map(|label| series.loc(label))
+                    .collect::<Vec<Option<&i32>>>();
             },
+        )
+    });
+
+    c.bench_function("series indexing (ILOC)", |b| {
+        b.iter_with_setup(
+            || Series::arange(0, 10000),
             |series| {
                 let _res = series.iloc(&vec![250, 500, 1000, 2000, 4000, 5000]);
             },
         )
     });
 
-    c.bench_function("series drops (DROP_INDEXES)", |b| {
+    c.bench_function("series indexing (ILOC 10M)", |b| {
         b.iter_with_setup(
             || {
-                let series = Series::arange(0, 10000);
-                series
+                let series = Series::arange(0, 10_000_000);
+                let positions = (0..10_000_000).rev().collect::<Vec<usize>>();
+                (series, positions)
+            },
+            |(series, positions)| {
+                let _res = series.iloc(&positions);
             },
+        )
+    });
+
+    c.bench_function("series indexing (ILOC_PAR 10M)", |b| {
+        b.iter_with_setup(
+            || {
+                let series = Series::arange(0, 10_000_000);
+                let positions = (0..10_000_000).rev().collect::<Vec<usize>>();
+                (series, positions)
+            },
+            |(series, positions)| {
+                let _res = series.iloc_par_with_threshold(&positions, 0);
+            },
+        )
+    });
+
+    c.bench_function("dataframe drops (DROP_POSITIONS 50 columns)", |b| {
+        b.iter_with_setup(
+            || {
+                let mut df = DataFrame::new();
+                for _ in 0..50 {
+                    df.add_column(Series::arange(0, 2000)).unwrap();
+                }
+                df
+            },
+            |mut df| {
+                df.drop_positions((0..2000).step_by(2));
+            },
+        )
+    });
+
+    c.bench_function("series drops (DROP_INDEXES)", |b| {
+        b.iter_with_setup(
+            || Series::arange(0, 10000),
             |mut series| {
                 series.drop_indexes(vec![250, 500, 1000, 2000, 4000, 5000]);
             },
@@ -0,0 +1,161 @@
+//! Allocations made by `Series::groupby`, compared with grouping by cloning each
+//! value into its group, as `Series::groupby` used to.
+//!
+//! Kept apart from the timing benchmarks, as counting allocations slows down
+//! every allocation made by the benchmark binary.
+
+#[macro_use]
+extern crate criterion;
+extern crate blackjack;
+extern crate indexmap;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use blackjack::prelude::*;
+use criterion::measurement::{Measurement, ValueFormatter};
+use criterion::{BatchSize, Criterion, Throughput};
+use indexmap::IndexMap;
+
+/// Allocator counting the allocations made
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Measures the number of allocations made, rather than wall time
+struct Allocations;
+
+impl Measurement for Allocations {
+    type Intermediate = usize;
+    type Value = usize;
+
+    fn start(&self) -> Self::Intermediate {
+        ALLOCATIONS.load(Ordering::SeqCst)
+    }
+
+    fn end(&self, start: Self::Intermediate) -> Self::Value {
+        ALLOCATIONS.load(Ordering::SeqCst) - start
+    }
+
+    fn add(&self, v1: &Self::Value, v2: &Self::Value) -> Self::Value {
+        v1 + v2
+    }
+
+    fn zero(&self) -> Self::Value {
+        0
+    }
+
+    fn to_f64(&self, value: &Self::Value) -> f64 {
+        *value as f64
+    }
+
+    fn formatter(&self) -> &dyn ValueFormatter {
+        &AllocationsFormatter
+    }
+}
+
+struct AllocationsFormatter;
+
+impl ValueFormatter for AllocationsFormatter {
+    fn scale_values(&self, _typical_value: f64, _values: &mut [f64]) -> &'static str {
+        "allocs"
+    }
+
+    fn scale_throughputs(
+        &self,
+        _typical_value: f64,
+        throughput: &Throughput,
+        values: &mut [f64],
+    ) -> &'static str {
+        let n = match throughput {
+            Throughput::Bytes(n) | Throughput::Elements(n) => *n as f64,
+        };
+        for value in values.iter_mut() {
+            *value /= n;
+        }
+        "allocs/elem"
+    }
+
+    fn scale_for_machines(&self, _values: &mut [f64]) -> &'static str {
+        "allocs"
+    }
+}
+
+/// Group the way `Series::groupby` did before grouping positions; cloning the
+/// series, then each value into its group and each group into a new series.
+fn groupby_cloned(series: &Series<i32>, keys: &Series<i32>) -> SeriesGroupBy<i32> {
+    let values = series.values.clone();
+
+    let mut map: IndexMap<String, Vec<i32>> = IndexMap::new();
+    for (k, v) in keys.values.iter().zip(values.iter()) {
+        map.entry(k.to_string()).or_default().push(*v);
+    }
+
+    let groups = map
+        .iter()
+        .map(|(name, values)| {
+            let mut series = Series::from_vec(values.clone());
+            series.set_name(name.as_str());
+            series
+        })
+        .collect();
+    SeriesGroupBy::new(groups)
+}
+
+fn groupby_allocations(c: &mut Criterion<Allocations>) {
+    let setup = || {
+        let series = Series::arange(0, 1_000_000);
+        let keys = Series::from_vec((0..1_000_000).map(|v| v % 100_000).collect());
+        (keys, series)
+    };
+
+    let mut group = c.benchmark_group("series groupby allocations (1M, 100k groups)");
+    group.throughput(Throughput::Elements(1_000_000));
+    group.bench_function("positions", |b| {
+        b.iter_batched(
+            setup,
+            |(keys, series)| series.groupby(&keys),
+            BatchSize::PerIteration,
+        )
+    });
+    group.bench_function("cloned values", |b| {
+        b.iter_batched(
+            setup,
+            |(keys, series)| groupby_cloned(&series, &keys),
+            BatchSize::PerIteration,
+        )
+    });
+    group.finish();
+}
+
+fn benches() {
+    // Sampling targets are in units of the measurement, so these keep the number
+    // of iterations, each allocating ~1M times, reasonable. Counts don't vary
+    // between iterations, which the plots can't be drawn for, so they're disabled
+    // after the command line arguments, which would otherwise enable them again.
+    let mut criterion = Criterion::default()
+        .with_measurement(Allocations)
+        .sample_size(10)
+        .warm_up_time(Duration::from_millis(100))
+        .measurement_time(Duration::from_secs(2))
+        .configure_from_args()
+        .without_plots();
+    groupby_allocations(&mut criterion);
+}
+
+criterion_main!(benches);
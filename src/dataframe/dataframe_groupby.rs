@@ -12,6 +12,9 @@ where
     T: BlackJackData,
 {
     groups: Vec<SeriesGroupBy<T>>,
+    /// Labels of each group, named after the key column, when grouped by one of
+    /// the dataframe's own columns
+    key: Option<Series<T>>,
 }
 
 impl<T> DataFrameGroupBy<T>
@@ -21,26 +24,44 @@ where
     /// Construct a new [`DataFrameGroupBy`] from a collection of [`SeiresGroupBy`]
     /// structs; shouldn't be needed to be used directly.
     pub fn new(groups: Vec<SeriesGroupBy<T>>) -> Self {
-        DataFrameGroupBy { groups }
+        DataFrameGroupBy { groups, key: None }
+    }
+
+    /// Construct a new [`DataFrameGroupBy`] where `key` holds the label of each group,
+    /// which is passed through to aggregations rather than being aggregated itself.
+    pub(crate) fn with_key(groups: Vec<SeriesGroupBy<T>>, key: Series<T>) -> Self {
+        DataFrameGroupBy {
+            groups,
+            key: Some(key),
+        }
+    }
+
+    /// The label of each group, named after the key column, if grouped
+    /// with [`DataFrame::groupby_column`]; [`DataFrameGroupBy::sum`] is currently
+    /// the only aggregation which passes it through as a column.
+    pub fn key(&self) -> Option<&Series<T>> {
+        self.key.as_ref()
     }
 
     /// Sum this grouped dataframe object.
     /// basically calls `sum` in parallel on each grouped series collected.
+    ///
+    /// Summed columns keep the names of the columns they were grouped from. When
+    /// grouped with [`DataFrame::groupby_column`] the group labels are held as the
+    /// first column, see [`DataFrameGroupBy::key`].
     pub fn sum(&self) -> DataFrame<i32>
-    // TODO:
     where
-        T: BlackJackData + Copy + Sum + Num + Send + Ord,
+        T: BlackJackData + Copy + Sum + Num + Send + Sync + Ord,
     {
-        // TODO: Return result
-
         let mut df = DataFrame::new();
 
-        let _ = self
-            .groups
-            .iter()
-            .map(|series_groupby| series_groupby.sum())
-            .map(|series| df.add_column(series).unwrap())
-            .collect::<Vec<()>>();
+        // Every column has a value per group, under a name distinct from the others
+        if let Some(key) = &self.key {
+            df.add_column(key.clone()).unwrap();
+        }
+        for series_groupby in &self.groups {
+            df.add_column(series_groupby.sum()).unwrap();
+        }
         df
     }
 }
@@ -3,10 +3,23 @@
 //!
 
 use std::ffi::OsStr;
+use std::io::Read;
 use std::path::Path;
+use std::str::FromStr;
+
+use itertools::Itertools;
 
 use crate::prelude::*;
 
+#[cfg(feature = "parquet")]
+mod parquet_io;
+
+/// Delimiters considered when sniffing a file with [`Reader::infer_delimiter`]
+const DELIMITER_CANDIDATES: [u8; 4] = [b',', b'\t', b';', b'|'];
+
+/// Number of lines sampled when sniffing a file with [`Reader::infer_delimiter`]
+const DELIMITER_SNIFF_LINES: usize = 10;
+
 /// DataFrame reading struct
 ///
 /// ## Example
@@ -29,6 +42,10 @@ pub struct Reader {
     quote: u8,
     has_headers: bool,
     header: Option<Vec<String>>,
+    na_values: Vec<String>,
+    infer_delimiter: bool,
+    float_dtype: DType,
+    usecols: Option<Vec<String>>,
 }
 
 /// DataFrame reading struct
@@ -64,6 +81,10 @@ impl Reader {
             quote: b'"',
             has_headers: true,
             header: None,
+            na_values: vec![],
+            infer_delimiter: false,
+            float_dtype: DType::F32,
+            usecols: None,
         }
     }
 
@@ -92,6 +113,28 @@ impl Reader {
     pub fn delimiter(self, delimiter: u8) -> Self {
         let mut rdr = self;
         rdr.delimiter = delimiter;
+        rdr.infer_delimiter = false;
+        rdr
+    }
+
+    /// Infer the delimiter by sniffing the first few lines of the file.
+    ///
+    /// Candidates are `,`, `\t`, `;` and `|`; the one occurring the same, non-zero,
+    /// number of times on each line is chosen. If no candidate is consistent, the
+    /// delimiter set by [`Reader::delimiter`] is used.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let path = format!("{}/tests/data/basic_tsv.tsv", env!("CARGO_MANIFEST_DIR"));
+    /// let df = Reader::new(&path).infer_delimiter().read().unwrap();
+    ///
+    /// assert_eq!(df.n_columns(), 3);
+    /// ```
+    pub fn infer_delimiter(self) -> Self {
+        let mut rdr = self;
+        rdr.infer_delimiter = true;
         rdr
     }
 
@@ -102,29 +145,218 @@ impl Reader {
         rdr
     }
 
+    /// Set tokens which represent missing data, ie. `"NA"`, `""` or `"null"`
+    ///
+    /// Numeric columns containing these tokens are parsed as floats, with `NaN`
+    /// in place of the missing fields, rather than falling back to `String`.
+    pub fn na_values(self, tokens: Vec<String>) -> Self {
+        let mut rdr = self;
+        rdr.na_values = tokens;
+        rdr
+    }
+
+    /// Set the float type non-integer numeric columns are parsed into, default is `DType::F32`
+    ///
+    /// Use `DType::F64` for columns whose values need more precision than an `f32`
+    /// can hold; any other dtype keeps the default.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let path = format!("{}/tests/data/basic_csv.csv", env!("CARGO_MANIFEST_DIR"));
+    /// let df = Reader::new(&path).float_precision(DType::F64).read().unwrap();
+    ///
+    /// let col1: &Series<f64> = df.get_column("col1").unwrap();
+    /// assert_eq!(col1.sum() as i32, 15);
+    /// ```
+    pub fn float_precision(self, dtype: DType) -> Self {
+        let mut rdr = self;
+        rdr.float_dtype = match dtype {
+            DType::F64 => DType::F64,
+            _ => DType::F32,
+        };
+        rdr
+    }
+
+    /// Set the only columns to read into the [`DataFrame`] by [`Reader::read`],
+    /// the fields of all others are skipped rather than parsed. Columns keep the
+    /// order they have in the file.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let path = format!("{}/tests/data/basic_csv.csv", env!("CARGO_MANIFEST_DIR"));
+    /// let df = Reader::new(&path).usecols(vec!["col2".to_string()]).read().unwrap();
+    ///
+    /// assert_eq!(df.n_columns(), 1);
+    /// ```
+    pub fn usecols(self, names: Vec<String>) -> Self {
+        let mut rdr = self;
+        rdr.usecols = Some(names);
+        rdr
+    }
+
     /// Read a CSV file into a [`DataFrame`] where each column represents a Series
     /// supports automatic decompression of gzipped files if they end with `.gz`
     pub fn read(&self) -> Result<DataFrame<i32>, BlackJackError> {
-        use flate2::read::GzDecoder;
-        use std::fs::File;
-        use std::io::prelude::*;
+        let mut df = DataFrame::new();
+        for column in self.read_columns(self.usecols.as_deref())? {
+            match column {
+                GenericSeriesContainer::I64(series) => df.add_column(series)?,
+                GenericSeriesContainer::F64(series) => df.add_column(series)?,
+                GenericSeriesContainer::I32(series) => df.add_column(series)?,
+                GenericSeriesContainer::F32(series) => df.add_column(series)?,
+                GenericSeriesContainer::STRING(series) => df.add_column(series)?,
+                GenericSeriesContainer::BOOL(series) => df.add_column(series)?,
+            }
+        }
+        Ok(df)
+    }
 
-        let p = Path::new(&self.path);
-        let file_reader: Box<Read> = if self.path.to_string().to_lowercase().ends_with(".gz") {
-            // Return a Gzip reader
-            Box::new(GzDecoder::new(File::open(p)?))
+    /// Read only the column `name` from a CSV file, skipping the fields of all
+    /// other columns; see [`Series::read_csv_column`].
+    pub(crate) fn read_column(&self, name: &str) -> Result<GenericSeriesContainer, BlackJackError> {
+        let mut columns = self.read_columns(Some(&[name.to_string()]))?;
+        Ok(columns.remove(0))
+    }
+
+    /// Read the columns named in `usecols`, or all of them if `None`, into series
+    /// in the order they have in the file.
+    fn read_columns(
+        &self,
+        usecols: Option<&[String]>,
+    ) -> Result<Vec<GenericSeriesContainer>, BlackJackError> {
+        let mut reader = self.csv_reader()?;
+        let headers = self.read_headers(&mut reader)?;
+
+        let positions: Vec<usize> = match usecols {
+            Some(names) => {
+                if let Some(name) = names.iter().find(|name| !headers.contains(name)) {
+                    return Err(BlackJackError::ValueError(format!(
+                        "No column named '{}'",
+                        name
+                    )));
+                }
+                (0..headers.len())
+                    .filter(|position| names.contains(&headers[*position]))
+                    .collect()
+            }
+            None => (0..headers.len()).collect(),
+        };
+
+        // Containers for storing column data
+        let mut vecs: Vec<Vec<String>> = positions.iter().map(|_| Vec::new()).collect();
+
+        for record in reader.records() {
+            let record = record?;
+            for (position, container) in positions.iter().zip(&mut vecs) {
+                if let Some(field) = record.get(*position) {
+                    container.push(field.into());
+                }
+            }
+        }
+
+        // map headers to vectors containing it's fields and into
+        // Series structs, parsing each field.
+        // TODO: Parallelize this operation, parse && serialize columns in parallel, then add them.
+        let columns = positions
+            .into_iter()
+            .zip(vecs)
+            .map(|(position, vec)| self.parse_column(&headers[position], vec))
+            .collect();
+        Ok(columns)
+    }
+
+    /// Parse the fields of a column into a series, trying `i32`, then the
+    /// [`Reader::float_precision`] float type and falling back to `String`.
+    fn parse_column(&self, header: &str, vec: Vec<String>) -> GenericSeriesContainer {
+        let mut series = Series::from_vec(vec);
+        series.set_name(header);
+        if let Ok(ser) = series.astype::<i32>() {
+            GenericSeriesContainer::I32(ser)
+        } else if self.float_dtype == DType::F64 {
+            if let Ok(ser) = series.astype::<f64>() {
+                GenericSeriesContainer::F64(ser)
+            } else if let Some(ser) = self.parse_with_na_values::<f64>(&series) {
+                GenericSeriesContainer::F64(ser)
+            } else {
+                GenericSeriesContainer::STRING(series)
+            }
+        } else if let Ok(ser) = series.astype::<f32>() {
+            GenericSeriesContainer::F32(ser)
+        } else if let Some(ser) = self.parse_with_na_values::<f32>(&series) {
+            GenericSeriesContainer::F32(ser)
         } else {
-            // Return plain file reader
-            Box::new(File::open(p)?)
+            GenericSeriesContainer::STRING(series)
+        }
+    }
+
+    /// Stream the rows of a CSV file lazily, without building the whole [`DataFrame`],
+    /// allowing for constant memory passes over files larger than memory.
+    ///
+    /// Each [`OwnedRow`] owns its values, so it outlives the reader's internal buffer.
+    /// Unlike [`Reader::read`], dtype inference is string-based and done per field of
+    /// each row, trying `i32`, then the [`Reader::float_precision`] float type and
+    /// falling back to `String`; the same column may therefore yield different
+    /// [`DataElement`] variants across rows.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let path = format!("{}/tests/data/basic_csv.csv", env!("CARGO_MANIFEST_DIR"));
+    ///
+    /// let mut total = 0;
+    /// for row in Reader::new(&path).stream().unwrap() {
+    ///     if let DataElement::I32(val) = row.unwrap()["col2"] {
+    ///         total += val;
+    ///     }
+    /// }
+    /// assert_eq!(total, 15);
+    /// ```
+    pub fn stream(
+        &self,
+    ) -> Result<impl Iterator<Item = Result<OwnedRow, BlackJackError>>, BlackJackError> {
+        let mut reader = self.csv_reader()?;
+        let headers = self.read_headers(&mut reader)?;
+        let na_values = self.na_values.clone();
+        let float_dtype = self.float_dtype.clone();
+
+        let rows = reader.into_records().map(move |record| {
+            let record = record?;
+            let mut row = OwnedRow::new();
+            for (name, field) in headers.iter().zip(record.iter()) {
+                row.add(name.clone(), parse_field(field, &na_values, &float_dtype));
+            }
+            Ok(row)
+        });
+        Ok(rows)
+    }
+
+    /// Build the underlying CSV reader from the current settings
+    fn csv_reader(&self) -> Result<csv::Reader<Box<dyn Read>>, BlackJackError> {
+        let delimiter = if self.infer_delimiter {
+            self.sniff_delimiter()?.unwrap_or(self.delimiter)
+        } else {
+            self.delimiter
         };
 
-        let mut reader = csv::ReaderBuilder::new()
+        let reader = csv::ReaderBuilder::new()
             .quote(self.quote)
             .has_headers(self.has_headers)
-            .delimiter(self.delimiter)
+            .delimiter(delimiter)
             .terminator(self.terminator)
-            .from_reader(file_reader);
+            .from_reader(self.file_reader()?);
+        Ok(reader)
+    }
 
+    /// Get the headers from the file, or those supplied if the file has none
+    fn read_headers(
+        &self,
+        reader: &mut csv::Reader<Box<dyn Read>>,
+    ) -> Result<Vec<String>, BlackJackError> {
         let headers: Vec<String> = if self.has_headers {
             reader
                 .headers()?
@@ -144,44 +376,107 @@ impl Reader {
                 }
             }
         };
+        Ok(headers)
+    }
 
-        // Containers for storing column data
-        let mut vecs: Vec<Vec<String>> = (0..headers.len()).map(|_| Vec::new()).collect();
+    /// Open the file, decompressing it if it ends with `.gz`
+    fn file_reader(&self) -> Result<Box<dyn Read>, BlackJackError> {
+        use flate2::read::GzDecoder;
+        use std::fs::File;
 
-        for record in reader.records() {
-            match record {
-                Ok(rec) => {
-                    for (field, container) in rec.iter().zip(&mut vecs) {
-                        container.push(field.into());
+        let p = Path::new(&self.path);
+        let file_reader: Box<dyn Read> = if self.path.to_string().to_lowercase().ends_with(".gz") {
+            // Return a Gzip reader
+            Box::new(GzDecoder::new(File::open(p)?))
+        } else {
+            // Return plain file reader
+            Box::new(File::open(p)?)
+        };
+        Ok(file_reader)
+    }
+
+    /// Sniff the first lines of the file for a delimiter which occurs a consistent
+    /// number of times per line, ignoring any found within quotes.
+    fn sniff_delimiter(&self) -> Result<Option<u8>, BlackJackError> {
+        use std::io::{BufRead, BufReader};
+
+        let lines = BufReader::new(self.file_reader()?)
+            .lines()
+            .take(DELIMITER_SNIFF_LINES)
+            .collect::<Result<Vec<String>, _>>()?;
+
+        let count_unquoted = |line: &str, delimiter: u8| {
+            let mut in_quotes = false;
+            line.bytes()
+                .filter(|b| {
+                    if *b == self.quote {
+                        in_quotes = !in_quotes;
                     }
+                    !in_quotes && *b == delimiter
+                })
+                .count()
+        };
+
+        // Reversed so ties are won by the earlier candidate, as `max_by_key` keeps the last max
+        let delimiter = DELIMITER_CANDIDATES
+            .iter()
+            .rev()
+            .filter_map(|candidate| {
+                let counts = lines
+                    .iter()
+                    .map(|line| count_unquoted(line, *candidate))
+                    .collect::<Vec<usize>>();
+                match counts.first() {
+                    Some(count) if *count > 0 && counts.iter().all_equal() => {
+                        Some((*candidate, *count))
+                    }
+                    _ => None,
                 }
+            })
+            .max_by_key(|(_candidate, count)| *count)
+            .map(|(candidate, _count)| candidate);
+        Ok(delimiter)
+    }
 
-                // TODO: Process for dealing with invalid records.
-                Err(err) => println!("Unable to read record: '{}'", err),
-            }
+    /// Parse a column as floats, where the only fields allowed to fail are those
+    /// matching one of the `na_values` tokens; those become `NaN`.
+    fn parse_with_na_values<A>(&self, series: &Series<String>) -> Option<Series<A>>
+    where
+        A: BlackJackData + FromStr + Default,
+    {
+        if self.na_values.is_empty() {
+            return None;
         }
+        let (parsed, failed) = series.try_astype::<A>();
+        if failed
+            .iter()
+            .all(|position| self.na_values.contains(&series[*position]))
+        {
+            Some(parsed)
+        } else {
+            None
+        }
+    }
+}
 
-        let mut df = DataFrame::new();
-
-        // map headers to vectors containing it's fields in parallel and into
-        // Series structs, parsing each field.
-        // TODO: Parallelize this operation, parse && serialize columns in parallel, then add them.
-        let _ = headers
-            .into_iter()
-            .zip(vecs)
-            .map(|(header, vec)| {
-                let mut series = Series::from_vec(vec);
-                series.set_name(&header);
-                if let Ok(ser) = series.astype::<i32>() {
-                    df.add_column(ser).unwrap();
-                } else if let Ok(ser) = series.astype::<f32>() {
-                    df.add_column(ser).unwrap()
-                } else {
-                    df.add_column(series).unwrap()
-                }
-            })
-            .collect::<Vec<()>>();
-        Ok(df)
+/// Parse a single field, trying `i32`, then a float of `float_dtype` and falling back
+/// to `String`. Fields matching one of `na_values` become a `NaN` float.
+fn parse_field(field: &str, na_values: &[String], float_dtype: &DType) -> DataElement {
+    if let Ok(val) = field.parse::<i32>() {
+        return DataElement::I32(val);
+    }
+    let is_na = na_values.iter().any(|na| na == field);
+    match float_dtype {
+        DType::F64 => match field.parse::<f64>() {
+            Ok(val) => DataElement::F64(val),
+            Err(_) if is_na => DataElement::F64(num::Float::nan()),
+            Err(_) => DataElement::STR(field.to_string()),
+        },
+        _ => match field.parse::<f32>() {
+            Ok(val) => DataElement::F32(val),
+            Err(_) if is_na => DataElement::F32(num::Float::nan()),
+            Err(_) => DataElement::STR(field.to_string()),
+        },
     }
 }
 
@@ -225,11 +520,33 @@ impl Writer {
         wtr
     }
 
-    /// Write a dataframe to CSV, consumes self, and thus will not double memory whilst
-    /// writing to CSV.
+    /// Write a dataframe to CSV, consuming the dataframe.
+    ///
+    /// See [`Writer::write_ref`] to write a dataframe which is still needed afterwards.
     pub fn write<I: PartialEq + PartialOrd + BlackJackData>(
         &self,
         df: DataFrame<I>,
+    ) -> Result<(), BlackJackError> {
+        self.write_ref(&df)
+    }
+
+    /// Write a dataframe to CSV from a reference, leaving the dataframe available
+    /// for further use.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::arange(0, 10)).unwrap();
+    ///
+    /// let writer = Writer::new(&"/tmp/test_write_ref.csv");
+    /// assert!(writer.write_ref(&df).is_ok());
+    /// assert_eq!(df.len(), 10);  // Still have our dataframe
+    /// ```
+    pub fn write_ref<I: PartialEq + PartialOrd + BlackJackData>(
+        &self,
+        df: &DataFrame<I>,
     ) -> Result<(), BlackJackError> {
         use flate2::read::GzEncoder;
         use flate2::Compression;
@@ -253,11 +570,16 @@ impl Writer {
             .terminator(self.terminator)
             .from_writer(file_writer);
 
-        let header = df.columns().map(|v| v.to_string()).collect::<Vec<String>>();
+        // Header and data both follow the column order held in `meta`
+        let header = df
+            .meta
+            .iter()
+            .map(|meta| meta.name.clone())
+            .collect::<Vec<String>>();
 
         // Deserialize all series into string vecs
         let mut data = vec![];
-        for col_name in df.data.keys() {
+        for col_name in header.iter() {
             let series_container = df.get_column_infer(col_name.as_str()).unwrap();
             let string_vec = series_container.into_string_vec();
             data.push(string_vec);
@@ -270,7 +592,7 @@ impl Writer {
         };
 
         // TODO: Probably a better way to do this?
-        for row_idx in 0..data[0].len() {
+        for row_idx in 0..df.len() {
             let mut row = vec![];
             for column_idx in 0..data.len() {
                 row.push(&data[column_idx][row_idx]);
@@ -0,0 +1,231 @@
+//!
+//! Parquet reading and writing for DataFrames, enabled with the `parquet` feature.
+//!
+
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, ArrayRef, BooleanArray, Float32Array, Float64Array, Int32Array, Int64Array, StringArray,
+};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+
+use crate::prelude::*;
+
+impl Reader {
+    /// Read a Parquet file into a [`DataFrame`], where each column represents a Series
+    ///
+    /// Supports the Parquet equivalents of each [`DType`]; other column types will
+    /// result in an error. Null floats are read as `NaN`, while nulls in other column
+    /// types are an error.
+    pub fn read_parquet(&self) -> Result<DataFrame<i32>, BlackJackError> {
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(Path::new(&self.path))?)?;
+        let schema = builder.schema().clone();
+
+        let mut columns = schema
+            .fields()
+            .iter()
+            .map(|field| empty_column(field))
+            .collect::<Result<Vec<GenericSeriesContainer>, _>>()?;
+
+        for batch in builder.build()? {
+            let batch = batch?;
+            for (column, array) in columns.iter_mut().zip(batch.columns()) {
+                extend_column(column, array)?;
+            }
+        }
+
+        // Each column's dtype follows from the field's type, even without any rows
+        let mut df = DataFrame::new();
+        for (field, column) in schema.fields().iter().zip(columns) {
+            match column {
+                GenericSeriesContainer::I64(mut series) => {
+                    series.set_name(field.name());
+                    df.add_column(series)?
+                }
+                GenericSeriesContainer::F64(mut series) => {
+                    series.set_name(field.name());
+                    df.add_column(series)?
+                }
+                GenericSeriesContainer::I32(mut series) => {
+                    series.set_name(field.name());
+                    df.add_column(series)?
+                }
+                GenericSeriesContainer::F32(mut series) => {
+                    series.set_name(field.name());
+                    df.add_column(series)?
+                }
+                GenericSeriesContainer::STRING(mut series) => {
+                    series.set_name(field.name());
+                    df.add_column(series)?
+                }
+                GenericSeriesContainer::BOOL(mut series) => {
+                    series.set_name(field.name());
+                    df.add_column(series)?
+                }
+            }
+        }
+        Ok(df)
+    }
+}
+
+impl Writer {
+    /// Write a dataframe to a Parquet file, mapping each [`DType`] to its Parquet equivalent.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::arange(0, 10)).unwrap();
+    ///
+    /// Writer::new(&"/tmp/test.parquet").write_parquet(&df).unwrap();
+    ///
+    /// let df = Reader::new(&"/tmp/test.parquet").read_parquet().unwrap();
+    /// assert_eq!(df.len(), 10);
+    /// ```
+    pub fn write_parquet<I: PartialEq + PartialOrd + BlackJackData>(
+        &self,
+        df: &DataFrame<I>,
+    ) -> Result<(), BlackJackError> {
+        let mut fields = vec![];
+        let mut arrays: Vec<ArrayRef> = vec![];
+
+        for meta in &df.meta {
+            let name = meta.name.as_str();
+            let (data_type, array): (DataType, ArrayRef) = match meta.dtype {
+                DType::F64 => {
+                    let series: &Series<f64> = df.get_column(name).unwrap();
+                    let array = Float64Array::from(series.values.clone());
+                    (DataType::Float64, Arc::new(array))
+                }
+                DType::I64 => {
+                    let series: &Series<i64> = df.get_column(name).unwrap();
+                    let array = Int64Array::from(series.values.clone());
+                    (DataType::Int64, Arc::new(array))
+                }
+                DType::F32 => {
+                    let series: &Series<f32> = df.get_column(name).unwrap();
+                    let array = Float32Array::from(series.values.clone());
+                    (DataType::Float32, Arc::new(array))
+                }
+                DType::I32 => {
+                    let series: &Series<i32> = df.get_column(name).unwrap();
+                    let array = Int32Array::from(series.values.clone());
+                    (DataType::Int32, Arc::new(array))
+                }
+                DType::STRING => {
+                    let series: &Series<String> = df.get_column(name).unwrap();
+                    let array = StringArray::from(series.values.clone());
+                    (DataType::Utf8, Arc::new(array))
+                }
+                DType::BOOL => {
+                    let series: &Series<bool> = df.get_column(name).unwrap();
+                    let array = BooleanArray::from(series.values.clone());
+                    (DataType::Boolean, Arc::new(array))
+                }
+            };
+            fields.push(Field::new(name, data_type, false));
+            arrays.push(array);
+        }
+
+        let schema = Arc::new(Schema::new(fields));
+        let batch = RecordBatch::try_new(schema.clone(), arrays)?;
+
+        let mut writer = ArrowWriter::try_new(File::create(Path::new(&self.path))?, schema, None)?;
+        writer.write(&batch)?;
+        writer.close()?;
+        Ok(())
+    }
+}
+
+/// Create an empty series container matching the Parquet field's type
+fn empty_column(field: &Field) -> Result<GenericSeriesContainer, BlackJackError> {
+    let container = match field.data_type() {
+        DataType::Float64 => GenericSeriesContainer::F64(Series::default()),
+        DataType::Int64 => GenericSeriesContainer::I64(Series::default()),
+        DataType::Float32 => GenericSeriesContainer::F32(Series::default()),
+        DataType::Int32 => GenericSeriesContainer::I32(Series::default()),
+        DataType::Utf8 => GenericSeriesContainer::STRING(Series::default()),
+        DataType::Boolean => GenericSeriesContainer::BOOL(Series::default()),
+        other => {
+            return Err(BlackJackError::ValueError(format!(
+                "Unsupported Parquet type: {} for column: {}",
+                other,
+                field.name()
+            )))
+        }
+    };
+    Ok(container)
+}
+
+/// Extend a series container with the values of an array from a record batch,
+/// returning an error if the array's type doesn't match the container
+fn extend_column(
+    column: &mut GenericSeriesContainer,
+    array: &ArrayRef,
+) -> Result<(), BlackJackError> {
+    let null_error = || BlackJackError::from("Null values are only supported in float columns");
+    let type_error = || {
+        BlackJackError::ValueError(format!(
+            "Unexpected Parquet type: {} in record batch",
+            array.data_type()
+        ))
+    };
+
+    match column {
+        GenericSeriesContainer::F64(series) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<Float64Array>()
+                .ok_or_else(type_error)?;
+            series.extend(array.iter().map(|v| v.unwrap_or(f64::NAN)));
+        }
+        GenericSeriesContainer::I64(series) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .ok_or_else(type_error)?;
+            let values = array.iter().map(|v| v.ok_or_else(null_error));
+            series.extend(values.collect::<Result<Vec<i64>, _>>()?);
+        }
+        GenericSeriesContainer::F32(series) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<Float32Array>()
+                .ok_or_else(type_error)?;
+            series.extend(array.iter().map(|v| v.unwrap_or(f32::NAN)));
+        }
+        GenericSeriesContainer::I32(series) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .ok_or_else(type_error)?;
+            let values = array.iter().map(|v| v.ok_or_else(null_error));
+            series.extend(values.collect::<Result<Vec<i32>, _>>()?);
+        }
+        GenericSeriesContainer::STRING(series) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .ok_or_else(type_error)?;
+            let values = array
+                .iter()
+                .map(|v| v.map(String::from).ok_or_else(null_error));
+            series.extend(values.collect::<Result<Vec<String>, _>>()?);
+        }
+        GenericSeriesContainer::BOOL(series) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<BooleanArray>()
+                .ok_or_else(type_error)?;
+            let values = array.iter().map(|v| v.ok_or_else(null_error));
+            series.extend(values.collect::<Result<Vec<bool>, _>>()?);
+        }
+    }
+    Ok(())
+}
@@ -2,14 +2,18 @@
 //!
 //!
 
+use std::collections::HashMap;
+
 use baggie::Baggie;
 use num::*;
+use rayon::prelude::*;
 use serde::Deserialize;
 
 use crate::prelude::*;
 
 pub mod dataframe_groupby;
 pub mod io;
+mod query;
 pub use self::dataframe_groupby::*;
 pub use self::io::*;
 
@@ -93,6 +97,57 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
         self.drop_positions(positions_to_drop.into_iter())
     }
 
+    /// Keep only the rows for which the expression `expr` holds, such as
+    /// `"col1 > 5 and col3 == foo"`.
+    ///
+    /// An expression compares columns against literals with `>`, `<`, `>=`, `<=`,
+    /// `==` or `!=`, joining comparisons with `and` / `or`, where `and` binds
+    /// tighter. Literals which parse as numbers are compared against numeric
+    /// columns, anything else, including text within quotes, against string and
+    /// bool columns. Values which can't be compared only satisfy `!=`.
+    ///
+    /// Returns an error, leaving the dataframe unchanged, if `expr` can't be parsed
+    /// or refers to a column which doesn't exist.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut s1 = Series::from(0..5);
+    /// s1.set_name("col1");
+    ///
+    /// let mut s2 = Series::from_vec(vec![
+    ///     "foo".to_string(),
+    ///     "bar".to_string(),
+    ///     "foo".to_string(),
+    ///     "bar".to_string(),
+    ///     "foo".to_string(),
+    /// ]);
+    /// s2.set_name("col2");
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(s1).unwrap();
+    /// df.add_column(s2).unwrap();
+    ///
+    /// df.query("col1 >= 1 and col2 == foo").unwrap();
+    /// assert_eq!(df.get_column::<i32>("col1").unwrap().values, vec![2, 4]);
+    /// ```
+    pub fn query(&mut self, expr: &str) -> Result<(), BlackJackError> {
+        let expr = query::Expr::parse(expr)?;
+        if let Some(column) = expr
+            .columns()
+            .into_iter()
+            .find(|column| !self.meta.iter().any(|meta| meta.name == *column))
+        {
+            return Err(BlackJackError::ValueError(format!(
+                "No column named '{}' in query",
+                column
+            )));
+        }
+        self.filter_by_row(|row| !expr.eval(row));
+        Ok(())
+    }
+
     /// Drop positions within the `Series`
     ///
     /// ## Example
@@ -108,32 +163,258 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
     /// ```
     pub fn drop_positions(&mut self, positions: impl Iterator<Item = usize>) -> () {
         let positions = positions.into_iter().collect::<Vec<usize>>();
-        for meta in self.meta.clone() {
-            match meta.dtype {
-                DType::F64 => {
-                    let s: &mut Series<f64> = &mut self.get_column_mut(meta.name.as_str()).unwrap();
-                    s.drop_positions(positions.clone())
-                }
-                DType::I64 => {
-                    let s: &mut Series<i64> = &mut self.get_column_mut(meta.name.as_str()).unwrap();
-                    s.drop_positions(positions.clone())
-                }
-                DType::F32 => {
-                    let s: &mut Series<f32> = &mut self.get_column_mut(meta.name.as_str()).unwrap();
-                    s.drop_positions(positions.clone())
-                }
-                DType::I32 => {
-                    let s: &mut Series<i32> = &mut self.get_column_mut(meta.name.as_str()).unwrap();
-                    s.drop_positions(positions.clone())
-                }
-                DType::STRING => {
-                    let s: &mut Series<String> =
-                        &mut self.get_column_mut(meta.name.as_str()).unwrap();
-                    s.drop_positions(positions.clone())
-                }
+
+        // Take the columns out of storage so they can be modified in parallel
+        let mut columns = self.take_columns();
+        columns
+            .par_iter_mut()
+            .for_each(|column| column.drop_positions(&positions));
+        self.restore_columns(columns);
+
+        self.index.drop_positions(positions);
+    }
+
+    /// Drop positions within the `DataFrame`, returning an error without modifying
+    /// the dataframe if any position is out of bounds.
+    ///
+    /// ## Example
+    /// ```
+    /// # use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from(0..10)).is_ok());
+    ///
+    /// assert!(df.try_drop_positions(5..11).is_err());
+    /// assert_eq!(df.len(), 10);
+    ///
+    /// assert!(df.try_drop_positions(0..5).is_ok());
+    /// assert_eq!(df.len(), 5);
+    /// ```
+    pub fn try_drop_positions(
+        &mut self,
+        positions: impl Iterator<Item = usize>,
+    ) -> Result<(), BlackJackError> {
+        let positions = positions.collect::<Vec<usize>>();
+        crate::series::check_positions(&positions, self.len())?;
+        self.drop_positions(positions.into_iter());
+        Ok(())
+    }
+
+    /// Keep only the rows where `mask` is `true`, dropping all others.
+    ///
+    /// ## Example
+    /// ```
+    /// # use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from(0..10)).is_ok());
+    ///
+    /// let mask = df.get_column::<i32>("col_0").unwrap().lt(3);
+    /// assert!(df.filter_mask(&mask).is_ok());
+    /// assert_eq!(df.len(), 3);
+    /// ```
+    pub fn filter_mask(&mut self, mask: &Series<bool>) -> Result<(), BlackJackError> {
+        if mask.len() != self.len() {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "DataFrame has length: {}, but mask has length: {}",
+                self.len(),
+                mask.len()
+            )));
+        }
+        let positions = mask
+            .values
+            .iter()
+            .enumerate()
+            .filter(|(_, keep)| !**keep)
+            .map(|(position, _)| position)
+            .collect::<Vec<usize>>();
+        self.drop_positions(positions.into_iter());
+        Ok(())
+    }
+
+    /// Randomly partition the rows into a train and a test frame, returned in that
+    /// order, where the test frame holds `test_frac` (clamped to `0..=1`) of the rows.
+    /// Rows keep their original order within each frame, and passing a `seed` makes
+    /// the split reproducible.
+    ///
+    /// ## Example
+    /// ```
+    /// # use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from(0..10)).is_ok());
+    ///
+    /// let (train, test) = df.train_test_split(0.2, Some(42));
+    /// assert_eq!(train.len(), 8);
+    /// assert_eq!(test.len(), 2);
+    /// ```
+    pub fn train_test_split(
+        mut self,
+        test_frac: f64,
+        seed: Option<u64>,
+    ) -> (DataFrame<I>, DataFrame<I>) {
+        let seed = seed.unwrap_or_else(|| {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|duration| duration.as_nanos() as u64)
+                .unwrap_or_default()
+        });
+        let positions = crate::funcs::shuffled_positions(self.len(), seed);
+        let n_test = (self.len() as f64 * test_frac.clamp(0., 1.)).round() as usize;
+
+        let (test_positions, train_positions) = positions.split_at(n_test);
+        let mut test_positions = test_positions.to_vec();
+        let mut train_positions = train_positions.to_vec();
+        test_positions.sort_unstable();
+        train_positions.sort_unstable();
+
+        let columns = self.take_columns();
+        let train = self.select_rows(&columns, &train_positions);
+        let test = self.select_rows(&columns, &test_positions);
+        (train, test)
+    }
+
+    /// Select the `n` rows with the largest values in the numeric column `column`,
+    /// in descending order of those values. Rows holding `NaN` in the column are
+    /// never selected and ties keep their original order.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::from_vec(vec![3, 1, 4, 1, 5])).unwrap();
+    ///
+    /// let top = df.nlargest(2, "col_0").unwrap();
+    /// assert_eq!(top.get_column::<i32>("col_0").unwrap().values, vec![5, 4]);
+    /// ```
+    pub fn nlargest(&self, n: usize, column: &str) -> Result<DataFrame<I>, BlackJackError> {
+        let positions = self.sorted_positions(column, true)?;
+        Ok(self.copy_rows(&positions[..n.min(positions.len())]))
+    }
+
+    /// Select the `n` rows with the smallest values in the numeric column `column`,
+    /// in ascending order of those values; see [`DataFrame::nlargest`].
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::from_vec(vec![3, 1, 4, 1, 5])).unwrap();
+    ///
+    /// let bottom = df.nsmallest(3, "col_0").unwrap();
+    /// assert_eq!(bottom.get_column::<i32>("col_0").unwrap().values, vec![1, 1, 3]);
+    /// ```
+    pub fn nsmallest(&self, n: usize, column: &str) -> Result<DataFrame<I>, BlackJackError> {
+        let positions = self.sorted_positions(column, false)?;
+        Ok(self.copy_rows(&positions[..n.min(positions.len())]))
+    }
+
+    /// Drop rows holding missing (NaN) values in their float columns, where `how`
+    /// decides if any or all of a row's float values must be NaN for it to be dropped.
+    /// Columns of other dtypes can't hold NaNs and are not considered.
+    ///
+    /// ## Example
+    /// ```
+    /// # use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from_vec(vec![1., f64::NAN, 3.])).is_ok());
+    /// assert!(df.add_column(Series::from_vec(vec![1, 2, 3])).is_ok());
+    ///
+    /// df.dropna(DropHow::Any);
+    /// assert_eq!(df.len(), 2);
+    /// ```
+    pub fn dropna(&mut self, how: DropHow) {
+        let mut nan_counts = vec![0; self.len()];
+        let mut n_float_columns = 0;
+
+        for meta in self.meta.iter() {
+            let isna: Box<dyn Iterator<Item = bool>> = match meta.dtype {
+                DType::F64 => Box::new(self.get_column::<f64>(meta.name.as_str()).unwrap().isna()),
+                DType::F32 => Box::new(self.get_column::<f32>(meta.name.as_str()).unwrap().isna()),
+                _ => continue,
             };
+            n_float_columns += 1;
+            for (count, is_nan) in nan_counts.iter_mut().zip(isna) {
+                *count += is_nan as usize;
+            }
         }
-        self.index.drop_positions(positions);
+
+        let positions = nan_counts
+            .into_iter()
+            .enumerate()
+            .filter(|(_, count)| match how {
+                DropHow::Any => *count > 0,
+                DropHow::All => n_float_columns > 0 && *count == n_float_columns,
+            })
+            .map(|(position, _)| position)
+            .collect::<Vec<usize>>();
+        self.drop_positions(positions.into_iter());
+    }
+
+    /// Replace NaN values in every float column with `value`, see [`Series::fillna`].
+    /// Columns of other dtypes are left untouched.
+    ///
+    /// ## Example
+    /// ```
+    /// # use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from_vec(vec![1., f64::NAN])).is_ok());
+    ///
+    /// df.fillna(0.);
+    /// assert_eq!(df.get_column::<f64>("col_0").unwrap().values, vec![1., 0.]);
+    /// ```
+    pub fn fillna(&mut self, value: f64) {
+        let names = self
+            .meta
+            .iter()
+            .map(|meta| meta.name.clone())
+            .collect::<Vec<String>>();
+        for name in names {
+            // Columns which aren't floats are skipped
+            let _ = self.fillna_column(&name, value);
+        }
+    }
+
+    /// Replace NaN values in the float column `column` with `value`, returning
+    /// an error if there's no float column of that name.
+    ///
+    /// ## Example
+    /// ```
+    /// # use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from_vec(vec![1_f32, f32::NAN])).is_ok());
+    /// assert!(df.add_column(Series::from_vec(vec![1, 2])).is_ok());
+    ///
+    /// assert!(df.fillna_column("col_0", -1.).is_ok());
+    /// assert_eq!(df.get_column::<f32>("col_0").unwrap().values, vec![1., -1.]);
+    ///
+    /// assert!(df.fillna_column("col_1", -1.).is_err());
+    /// ```
+    pub fn fillna_column(&mut self, column: &str, value: f64) -> Result<(), BlackJackError> {
+        let dtype = self
+            .meta
+            .iter()
+            .find(|meta| meta.name == column)
+            .map(|meta| meta.dtype.clone());
+        match dtype {
+            Some(DType::F64) => self.get_column_mut::<f64>(column).unwrap().fillna(value),
+            Some(DType::F32) => self
+                .get_column_mut::<f32>(column)
+                .unwrap()
+                .fillna(value as f32),
+            _ => {
+                return Err(BlackJackError::ValueError(format!(
+                    "No float column named '{}'",
+                    column
+                )))
+            }
+        }
+        Ok(())
     }
 
     /// Iterator over rows of a dataframe where each element contained is a reference
@@ -152,34 +433,62 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
     /// assert!(rows.iter().all(|r| r.data.len() == 2));  // Each row has two elements
     /// ```
     pub fn iter_rows(&self) -> impl Iterator<Item = Row<'_>> {
-        (0..self.len()).map(move |idx| {
-            let mut row = Row::new();
-            for meta in self.meta.iter() {
-                match meta.dtype {
-                    DType::F64 => {
-                        let series: &Series<f64> = self.data.get(&meta.name).unwrap();
-                        row.add(Element::new(meta.name.clone(), Datum::F64(&series[idx])))
-                    }
-                    DType::I64 => {
-                        let series: &Series<i64> = self.data.get(&meta.name).unwrap();
-                        row.add(Element::new(meta.name.clone(), Datum::I64(&series[idx])))
-                    }
-                    DType::F32 => {
-                        let series: &Series<f32> = self.data.get(&meta.name).unwrap();
-                        row.add(Element::new(meta.name.clone(), Datum::F32(&series[idx])))
-                    }
-                    DType::I32 => {
-                        let series: &Series<i32> = self.data.get(&meta.name).unwrap();
-                        row.add(Element::new(meta.name.clone(), Datum::I32(&series[idx])))
-                    }
-                    DType::STRING => {
-                        let series: &Series<String> = self.data.get(&meta.name).unwrap();
-                        row.add(Element::new(meta.name.clone(), Datum::STR(&series[idx])))
-                    }
+        (0..self.len()).map(move |idx| self.row_at(idx))
+    }
+
+    /// Get the row at `position`, or `None` if it's out of bounds.
+    ///
+    /// ## Example
+    /// ```
+    /// # use blackjack::prelude::*;
+    /// let mut df = DataFrame::new();
+    /// assert!(df.add_column(Series::from_vec(vec![0, 1, 2, 3])).is_ok());
+    ///
+    /// let row = df.get_row(2).unwrap();
+    /// assert!(row["col_0"] == Datum::I32(&2));
+    ///
+    /// assert!(df.get_row(4).is_none());
+    /// ```
+    pub fn get_row(&self, position: usize) -> Option<Row<'_>> {
+        if position < self.len() {
+            Some(self.row_at(position))
+        } else {
+            None
+        }
+    }
+
+    /// Build the row at `idx`, which must be in bounds
+    fn row_at(&self, idx: usize) -> Row<'_> {
+        let mut row = Row::new();
+        for meta in self.meta.iter() {
+            match meta.dtype {
+                DType::F64 => {
+                    let series: &Series<f64> = self.data.get(&meta.name).unwrap();
+                    row.add(Element::new(meta.name.clone(), Datum::F64(&series[idx])))
+                }
+                DType::I64 => {
+                    let series: &Series<i64> = self.data.get(&meta.name).unwrap();
+                    row.add(Element::new(meta.name.clone(), Datum::I64(&series[idx])))
+                }
+                DType::F32 => {
+                    let series: &Series<f32> = self.data.get(&meta.name).unwrap();
+                    row.add(Element::new(meta.name.clone(), Datum::F32(&series[idx])))
+                }
+                DType::I32 => {
+                    let series: &Series<i32> = self.data.get(&meta.name).unwrap();
+                    row.add(Element::new(meta.name.clone(), Datum::I32(&series[idx])))
+                }
+                DType::STRING => {
+                    let series: &Series<String> = self.data.get(&meta.name).unwrap();
+                    row.add(Element::new(meta.name.clone(), Datum::STR(&series[idx])))
+                }
+                DType::BOOL => {
+                    let series: &Series<bool> = self.data.get(&meta.name).unwrap();
+                    row.add(Element::new(meta.name.clone(), Datum::BOOL(&series[idx])))
                 }
             }
-            row
-        })
+        }
+        row
     }
 
     /// Select rows of the DataFrame based on positional index
@@ -219,6 +528,103 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
             .map(|(_idx, row)| row)
     }
 
+    /// Materialize each `Row` of the dataframe into a typed struct implementing [`FromRow`]
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// struct Point {
+    ///     x: i32,
+    ///     label: String,
+    /// }
+    ///
+    /// impl FromRow for Point {
+    ///     fn from_row(row: &Row) -> Result<Self, BlackJackError> {
+    ///         match (row.get("x"), row.get("label")) {
+    ///             (Some(Datum::I32(x)), Some(Datum::STR(label))) => Ok(Point {
+    ///                 x: **x,
+    ///                 label: label.to_string(),
+    ///             }),
+    ///             _ => Err(BlackJackError::from("Unexpected row layout")),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut x = Series::from_vec(vec![1, 2]);
+    /// x.set_name("x");
+    /// let mut label = Series::from_vec(vec!["a".to_string(), "b".to_string()]);
+    /// label.set_name("label");
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(x).unwrap();
+    /// df.add_column(label).unwrap();
+    ///
+    /// let points: Vec<Point> = df.collect_rows().unwrap();
+    /// assert_eq!(points[1].x, 2);
+    /// assert_eq!(points[1].label, "b".to_string());
+    /// ```
+    pub fn collect_rows<R: FromRow>(&self) -> Result<Vec<R>, BlackJackError> {
+        self.iter_rows().map(|row| R::from_row(&row)).collect()
+    }
+
+    /// Consume the dataframe into rows which own their values, allowing them
+    /// to outlive the dataframe or be sent across threads.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::from_vec(vec![1, 2])).unwrap();
+    /// df.add_column(Series::from_vec(vec![0.5, 1.5])).unwrap();
+    ///
+    /// let rows = df.into_rows();
+    /// assert_eq!(rows.len(), 2);
+    /// assert_eq!(rows[1]["col_1"], DataElement::F64(1.5));
+    /// ```
+    pub fn into_rows(self) -> Vec<OwnedRow> {
+        let mut df = self;
+        let len = df.len();
+
+        let mut columns = df
+            .take_columns()
+            .into_iter()
+            .map(|column| -> Box<dyn Iterator<Item = DataElement>> {
+                match column {
+                    GenericSeriesContainer::F64(series) => {
+                        Box::new(series.into_iter().map(DataElement::F64))
+                    }
+                    GenericSeriesContainer::I64(series) => {
+                        Box::new(series.into_iter().map(DataElement::I64))
+                    }
+                    GenericSeriesContainer::F32(series) => {
+                        Box::new(series.into_iter().map(DataElement::F32))
+                    }
+                    GenericSeriesContainer::I32(series) => {
+                        Box::new(series.into_iter().map(DataElement::I32))
+                    }
+                    GenericSeriesContainer::STRING(series) => {
+                        Box::new(series.into_iter().map(DataElement::STR))
+                    }
+                    GenericSeriesContainer::BOOL(series) => {
+                        Box::new(series.into_iter().map(DataElement::BOOL))
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        (0..len)
+            .map(|_| {
+                let mut row = OwnedRow::new();
+                for (meta, column) in df.meta.iter().zip(columns.iter_mut()) {
+                    row.add(meta.name.clone(), column.next().unwrap());
+                }
+                row
+            })
+            .collect()
+    }
+
     /// Length of the dataframe
     ///
     /// ## Example
@@ -274,25 +680,267 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
         Ok(())
     }
 
-    /// Retrieves a mutable reference to the column
-    pub fn get_column_mut<'a, T>(&mut self, name: impl Into<&'a str>) -> Option<&mut Series<T>>
+    /// Add all of `other`'s columns to this dataframe, after its own columns.
+    /// Returns an error, leaving this dataframe unchanged, if the lengths differ
+    /// or any column name is already taken; see [`DataFrame::concat_columns_with_suffix`]
+    /// for renaming colliding columns instead.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::from_vec(vec![1, 2])).unwrap();
+    ///
+    /// let mut other = DataFrame::new();
+    /// let mut s = Series::from_vec(vec![3., 4.]);
+    /// s.set_name("other");
+    /// other.add_column(s).unwrap();
+    ///
+    /// df.concat_columns(other).unwrap();
+    /// assert_eq!(df.n_columns(), 2);
+    /// assert_eq!(df.get_column::<f64>("other").unwrap().values, vec![3., 4.]);
+    /// ```
+    pub fn concat_columns(&mut self, other: DataFrame<I>) -> Result<(), BlackJackError>
     where
-        T: BlackJackData + 'static,
+        Vec<I>: std::iter::FromIterator<i32>,
     {
-        let name = name.into();
-        for meta in &self.meta {
-            if meta.name == name {
-                let series: Option<&mut Series<T>> = self.data.get_mut(&meta.name);
-                return series;
-            }
-        }
-        None
+        self.concat_columns_renamed(other, None)
     }
 
-    /// Retrieves a reference to a column
-    pub fn get_column<'a, T>(&self, name: impl Into<&'a str>) -> Option<&Series<T>>
+    /// Same as [`DataFrame::concat_columns`], but columns of `other` whose names
+    /// are already taken get `suffix` appended to their names.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::from_vec(vec![1, 2])).unwrap();
+    ///
+    /// let mut other = DataFrame::new();
+    /// other.add_column(Series::from_vec(vec![3, 4])).unwrap();
+    ///
+    /// df.concat_columns_with_suffix(other, "_right").unwrap();
+    /// assert_eq!(df.get_column::<i32>("col_0_right").unwrap().values, vec![3, 4]);
+    /// ```
+    pub fn concat_columns_with_suffix(
+        &mut self,
+        other: DataFrame<I>,
+        suffix: &str,
+    ) -> Result<(), BlackJackError>
     where
-        T: BlackJackData + 'static,
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        self.concat_columns_renamed(other, Some(suffix))
+    }
+
+    fn concat_columns_renamed(
+        &mut self,
+        other: DataFrame<I>,
+        suffix: Option<&str>,
+    ) -> Result<(), BlackJackError>
+    where
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        if self.n_columns() > 0 && other.n_columns() > 0 && self.len() != other.len() {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "DataFrame has length: {}, cannot add columns of length: {}",
+                self.len(),
+                other.len()
+            )));
+        }
+
+        // Settle on every new name before moving anything over
+        let mut names: Vec<String> = self.meta.iter().map(|meta| meta.name.clone()).collect();
+        for meta in other.meta.iter() {
+            let mut name = meta.name.clone();
+            if names.contains(&name) {
+                if let Some(suffix) = suffix {
+                    name.push_str(suffix);
+                }
+            }
+            if names.contains(&name) {
+                return Err(BlackJackError::ValueError(format!(
+                    "Column named '{}' already exists",
+                    name
+                )));
+            }
+            names.push(name);
+        }
+
+        let new_names = names.split_off(self.n_columns());
+        for (meta, name) in other.meta.iter().zip(new_names) {
+            match other.get_column_infer(meta.name.as_str()).unwrap() {
+                GenericSeriesContainer::F64(mut series) => {
+                    series.set_name(&name);
+                    self.add_column(series)?
+                }
+                GenericSeriesContainer::I64(mut series) => {
+                    series.set_name(&name);
+                    self.add_column(series)?
+                }
+                GenericSeriesContainer::F32(mut series) => {
+                    series.set_name(&name);
+                    self.add_column(series)?
+                }
+                GenericSeriesContainer::I32(mut series) => {
+                    series.set_name(&name);
+                    self.add_column(series)?
+                }
+                GenericSeriesContainer::STRING(mut series) => {
+                    series.set_name(&name);
+                    self.add_column(series)?
+                }
+                GenericSeriesContainer::BOOL(mut series) => {
+                    series.set_name(&name);
+                    self.add_column(series)?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Add a column to this dataframe, padding it to the length of the dataframe if
+    /// it's shorter; with `NaN` for floats and `T::default()` otherwise. Returns an
+    /// error only if the column is longer than the dataframe.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::arange(0, 3)).unwrap();
+    /// df.add_column_padded(Series::from_vec(vec![1, 2])).unwrap();
+    ///
+    /// assert_eq!(df.get_column::<i32>("col_1").unwrap().values, vec![1, 2, 0]);
+    /// ```
+    pub fn add_column_padded<T>(&mut self, series: Series<T>) -> Result<(), BlackJackError>
+    where
+        T: BlackJackData + Default + 'static,
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        let mut series = series;
+        if series.len() < self.len() {
+            let fill = T::missing().unwrap_or_default();
+            series.resize(self.len(), fill);
+        }
+        self.add_column(series)
+    }
+
+    /// Add a copy of the numeric column `column` shifted forward by `lag` rows as
+    /// a new `f64` column named `new_name`, where the leading rows are `NaN`.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::from_vec(vec![1, 2, 3])).unwrap();
+    /// df.add_lag("col_0", 1, "lag_1").unwrap();
+    ///
+    /// let lagged: &Series<f64> = df.get_column("lag_1").unwrap();
+    /// assert!(lagged[0].is_nan());
+    /// assert_eq!(lagged[1..3].to_vec(), vec![1., 2.]);
+    /// ```
+    pub fn add_lag(
+        &mut self,
+        column: &str,
+        lag: usize,
+        new_name: &str,
+    ) -> Result<(), BlackJackError>
+    where
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        let values = self.numeric_column(column)?;
+        let mut lagged = values.shift_fill(lag as i64, f64::NAN);
+        lagged.set_name(new_name);
+        self.add_derived_column(lagged)
+    }
+
+    /// Add the difference between each value of the numeric column `column` and the
+    /// value `periods` rows before it as a new `f64` column named `new_name`, see
+    /// [`Series::diff`]. The leading rows are `NaN`.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::from_vec(vec![1, 2, 4])).unwrap();
+    /// df.add_diff("col_0", 1, "diff_1").unwrap();
+    ///
+    /// let diff: &Series<f64> = df.get_column("diff_1").unwrap();
+    /// assert!(diff[0].is_nan());
+    /// assert_eq!(diff[1..3].to_vec(), vec![1., 2.]);
+    /// ```
+    pub fn add_diff(
+        &mut self,
+        column: &str,
+        periods: usize,
+        new_name: &str,
+    ) -> Result<(), BlackJackError>
+    where
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        let mut diff = self.numeric_column(column)?.diff(periods);
+        diff.set_name(new_name);
+        self.add_derived_column(diff)
+    }
+
+    /// Add the `f64` column computed by `f` from this dataframe under `name`,
+    /// returning the dataframe to allow chaining. Returns an error if `name` is
+    /// already taken or the computed column has the wrong length.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::from_vec(vec![1., 4.])).unwrap();
+    /// df.add_column(Series::from_vec(vec![2., 8.])).unwrap();
+    ///
+    /// let df = df
+    ///     .assign("ratio", |d| {
+    ///         let numerator: &Series<f64> = d.get_column("col_0").unwrap();
+    ///         let denominator: &Series<f64> = d.get_column("col_1").unwrap();
+    ///         numerator.div_f64(denominator).unwrap()
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(df.get_column::<f64>("ratio").unwrap().values, vec![0.5, 0.5]);
+    /// ```
+    pub fn assign<F>(self, name: &str, f: F) -> Result<DataFrame<I>, BlackJackError>
+    where
+        F: Fn(&DataFrame<I>) -> Series<f64>,
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        let mut df = self;
+        let mut series = f(&df);
+        series.set_name(name);
+        df.add_derived_column(series)?;
+        Ok(df)
+    }
+
+    /// Retrieves a mutable reference to the column
+    pub fn get_column_mut<'a, T>(&mut self, name: impl Into<&'a str>) -> Option<&mut Series<T>>
+    where
+        T: BlackJackData + 'static,
+    {
+        let name = name.into();
+        for meta in &self.meta {
+            if meta.name == name {
+                let series: Option<&mut Series<T>> = self.data.get_mut(&meta.name);
+                return series;
+            }
+        }
+        None
+    }
+
+    /// Retrieves a reference to a column
+    pub fn get_column<'a, T>(&self, name: impl Into<&'a str>) -> Option<&Series<T>>
+    where
+        T: BlackJackData + 'static,
     {
         let name = name.into();
         for meta in &self.meta {
@@ -325,6 +973,9 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
                 DType::STRING => GenericSeriesContainer::STRING(
                     self.data.get::<Series<String>, _>(name).unwrap().clone(),
                 ),
+                DType::BOOL => {
+                    GenericSeriesContainer::BOOL(self.data.get::<Series<bool>, _>(name)?.clone())
+                }
             };
             Some(container)
         } else {
@@ -332,6 +983,155 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
         }
     }
 
+    /// Get any numeric column as `f64` values, regardless of its exact type.
+    /// Returns `None` if the column doesn't exist or isn't numeric.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::from_vec(vec![1, 2, 3])).unwrap();
+    ///
+    /// assert_eq!(df.column_as_f64("col_0"), Some(vec![1., 2., 3.]));
+    /// assert_eq!(df.column_as_f64("missing"), None);
+    /// ```
+    pub fn column_as_f64(&self, name: &str) -> Option<Vec<f64>> {
+        fn cast<T: BlackJackData + ToPrimitive>(series: Series<T>) -> Option<Vec<f64>> {
+            series.values.iter().map(|v| v.to_f64()).collect()
+        }
+        match self.get_column_infer(name)? {
+            GenericSeriesContainer::F64(series) => Some(series.into_vec()),
+            GenericSeriesContainer::I64(series) => cast(series),
+            GenericSeriesContainer::F32(series) => cast(series),
+            GenericSeriesContainer::I32(series) => cast(series),
+            GenericSeriesContainer::STRING(_) | GenericSeriesContainer::BOOL(_) => None,
+        }
+    }
+
+    /// Calculate the covariance matrix of the numeric columns, see [`Series::cov`].
+    ///
+    /// Each numeric column gets a column of the same name in the result, where
+    /// row `i` holds its covariance with the `i`th numeric column.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::from_vec(vec![1, 2, 3, 4])).unwrap();
+    /// df.add_column(Series::from_vec(vec![2., 4., 6., 8.])).unwrap();
+    ///
+    /// let cov = df.cov(1.);
+    /// let col_0: &Series<f64> = cov.get_column("col_0").unwrap();
+    /// assert_eq!(col_0.values, vec![5. / 3., 10. / 3.]);
+    /// ```
+    pub fn cov(&self, ddof: f64) -> DataFrame<i32> {
+        let numeric = self
+            .meta
+            .iter()
+            .filter_map(|meta| {
+                let mut series = Series::from_vec(self.column_as_f64(&meta.name)?);
+                series.set_name(&meta.name);
+                Some(series)
+            })
+            .collect::<Vec<Series<f64>>>();
+
+        let mut df = DataFrame::new();
+        for series in numeric.iter() {
+            let mut column = Series::from_vec(
+                numeric
+                    .iter()
+                    .map(|other| series.cov(other, ddof).unwrap_or(f64::NAN))
+                    .collect(),
+            );
+            column.name = series.name.clone();
+            df.add_column(column).unwrap();
+        }
+        df
+    }
+
+    /// Convert the column `name` to dtype `A` in place, see [`Series::astype`].
+    /// Returns an error, leaving the column unchanged, if it doesn't exist or
+    /// any of its values can't be converted.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::from_vec(vec!["1".to_string(), "2".to_string()])).unwrap();
+    ///
+    /// df.astype_column::<i64>("col_0").unwrap();
+    /// assert_eq!(df.get_column::<i64>("col_0").unwrap().values, vec![1, 2]);
+    /// ```
+    pub fn astype_column<A>(&mut self, name: &str) -> Result<(), BlackJackError>
+    where
+        A: BlackJackData + std::str::FromStr + 'static,
+    {
+        let column = self
+            .get_column_infer(name)
+            .ok_or_else(|| BlackJackError::ValueError(format!("No column named '{}'", name)))?;
+        let series = match column {
+            GenericSeriesContainer::F64(series) => series.astype::<A>(),
+            GenericSeriesContainer::I64(series) => series.astype::<A>(),
+            GenericSeriesContainer::F32(series) => series.astype::<A>(),
+            GenericSeriesContainer::I32(series) => series.astype::<A>(),
+            GenericSeriesContainer::STRING(series) => series.astype::<A>(),
+            GenericSeriesContainer::BOOL(series) => series.astype::<A>(),
+        }?;
+
+        // Taken from `A` itself, as an empty series has no values to take it from
+        if let Some(meta) = self.meta.iter_mut().find(|meta| meta.name == name) {
+            meta.dtype = A::dtype_of();
+        }
+        self.data.insert(name.to_string(), series);
+        Ok(())
+    }
+
+    /// Reorder the columns to follow `order`, which must name every column exactly
+    /// once. This is the order of the elements in each [`Row`] and of columns written
+    /// out by a [`Writer`].
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::from_vec(vec![1, 2])).unwrap();
+    /// df.add_column(Series::from_vec(vec![3, 4])).unwrap();
+    ///
+    /// assert!(df.reorder_columns(&["col_1", "col_0"]).is_ok());
+    /// assert!(df.reorder_columns(&["col_1"]).is_err());
+    /// ```
+    pub fn reorder_columns(&mut self, order: &[&str]) -> Result<(), BlackJackError> {
+        if order.len() != self.meta.len() {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "DataFrame has {} columns, but order names {}",
+                self.meta.len(),
+                order.len()
+            )));
+        }
+
+        let mut positions = Vec::with_capacity(order.len());
+        for name in order {
+            match self.meta.iter().position(|meta| meta.name == *name) {
+                Some(position) if !positions.contains(&position) => positions.push(position),
+                _ => {
+                    return Err(BlackJackError::ValueError(format!(
+                        "No column named '{}' left to order",
+                        name
+                    )))
+                }
+            }
+        }
+        self.meta = positions
+            .into_iter()
+            .map(|position| self.meta[position].clone())
+            .collect();
+        Ok(())
+    }
+
     /// Get a list of column names in this dataframe as an iterator
     pub fn columns(&self) -> impl Iterator<Item = &str> {
         self.data.keys().map(|c| c.as_str())
@@ -342,6 +1142,118 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
         self.data.len()
     }
 
+    /// Summarize the dataframe, listing its number of rows and, for each column,
+    /// its name, dtype and number of non-null values.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::from_vec(vec![1., f64::NAN])).unwrap();
+    ///
+    /// println!("{}", df.info());
+    /// assert!(df.info().contains("col_0  F64  1 non-null"));
+    /// ```
+    pub fn info(&self) -> String {
+        let width = self
+            .meta
+            .iter()
+            .map(|meta| meta.name.len())
+            .max()
+            .unwrap_or(0);
+        let mut info = format!("{} rows, {} columns\n", self.len(), self.n_columns());
+        for meta in &self.meta {
+            info.push_str(&format!(
+                "{:width$}  {:?}  {} non-null\n",
+                meta.name,
+                meta.dtype,
+                self.non_null_count(meta),
+                width = width
+            ));
+        }
+        info
+    }
+
+    /// Check the dataframe has exactly the `expected` columns, each of the expected
+    /// dtype, in any order. Returns an error listing every missing, unexpected or
+    /// mistyped column otherwise.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::from_vec(vec![1., 2.])).unwrap();
+    ///
+    /// assert!(df.validate_schema(&[("col_0".to_string(), DType::F64)]).is_ok());
+    /// assert!(df.validate_schema(&[("col_0".to_string(), DType::I32)]).is_err());
+    /// ```
+    pub fn validate_schema(&self, expected: &[(String, DType)]) -> Result<(), BlackJackError> {
+        let mut mismatches = vec![];
+        for (name, dtype) in expected {
+            match self.meta.iter().find(|meta| &meta.name == name) {
+                Some(meta) if &meta.dtype != dtype => mismatches.push(format!(
+                    "column '{}' has dtype {:?}, expected {:?}",
+                    name, meta.dtype, dtype
+                )),
+                Some(_) => (),
+                None => mismatches.push(format!("column '{}' is missing", name)),
+            }
+        }
+        for meta in &self.meta {
+            if !expected.iter().any(|(name, _)| name == &meta.name) {
+                mismatches.push(format!("column '{}' is unexpected", meta.name));
+            }
+        }
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(BlackJackError::ValueError(format!(
+                "DataFrame does not match schema: {}",
+                mismatches.join(", ")
+            )))
+        }
+    }
+
+    /// Number of non-null values in each column, where `NaN` counts as null in
+    /// float columns and other columns have no nulls.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::from_vec(vec![1., f64::NAN])).unwrap();
+    /// df.add_column(Series::from_vec(vec![1, 2])).unwrap();
+    ///
+    /// let counts = df.non_null_counts();
+    /// assert_eq!(counts["col_0"], 1);
+    /// assert_eq!(counts["col_1"], 2);
+    /// ```
+    pub fn non_null_counts(&self) -> HashMap<String, usize> {
+        self.meta
+            .iter()
+            .map(|meta| (meta.name.clone(), self.non_null_count(meta)))
+            .collect()
+    }
+
+    /// Number of non-NaN values in a float column, otherwise its length
+    fn non_null_count(&self, meta: &SeriesMeta) -> usize {
+        match meta.dtype {
+            DType::F64 => {
+                let series: &Series<f64> = self.data.get(&meta.name).unwrap();
+                series.isna().filter(|is_na| !is_na).count()
+            }
+            DType::F32 => {
+                let series: &Series<f32> = self.data.get(&meta.name).unwrap();
+                series.isna().filter(|is_na| !is_na).count()
+            }
+            DType::I64 | DType::I32 | DType::STRING | DType::BOOL => self.len(),
+        }
+    }
+
     /// Group by method for grouping [`Series`] in a [`DataFrame`]
     /// by key.
     pub fn groupby<T>(&self, keys: &Series<T>) -> DataFrameGroupBy<T>
@@ -358,4 +1270,251 @@ impl<I: PartialOrd + PartialEq + BlackJackData> DataFrame<I> {
 
         DataFrameGroupBy::new(groups)
     }
+
+    /// Group by one of the dataframe's own columns, using it as the keys; see
+    /// [`DataFrame::groupby`]. Returns an error if there is no column named `name`
+    /// of type `T`.
+    ///
+    /// The key column itself isn't aggregated, instead [`DataFrameGroupBy::sum`]
+    /// holds it as a column of group labels, see [`DataFrameGroupBy::key`]. Only
+    /// the other columns of type `T` are grouped, in the order they appear in the
+    /// dataframe; columns of any other type are skipped.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// df.add_column(Series::from_vec(vec![1, 2, 1, 2])).unwrap();
+    /// df.add_column(Series::from_vec(vec![1, 2, 3, 4])).unwrap();
+    ///
+    /// let grouped = df.groupby_column::<i32>("col_0").unwrap();
+    /// assert_eq!(grouped.key().unwrap().values, vec![1, 2]);
+    ///
+    /// assert!(df.groupby_column::<i32>("missing").is_err());
+    /// ```
+    pub fn groupby_column<T>(&self, name: &str) -> Result<DataFrameGroupBy<T>, BlackJackError>
+    where
+        for<'de> T: BlackJackData + Deserialize<'de> + ToPrimitive + 'static,
+    {
+        let keys = self.get_column::<T>(name).ok_or_else(|| {
+            BlackJackError::ValueError(format!(
+                "No column named '{}' of type {} to group by",
+                name,
+                std::any::type_name::<T>()
+            ))
+        })?;
+
+        let groups = self
+            .meta
+            .iter()
+            .filter(|meta| meta.name != name)
+            .filter_map(|meta| self.get_column::<T>(meta.name.as_str()))
+            .map(|series| series.groupby(keys))
+            .collect::<Vec<SeriesGroupBy<T>>>();
+
+        let mut labels = keys.groupby(keys).first();
+        labels.set_name(name);
+
+        Ok(DataFrameGroupBy::with_key(groups, labels))
+    }
+
+    /// Fetch a numeric column as a `Series<f64>`, erroring if it's missing or not numeric
+    fn numeric_column(&self, name: &str) -> Result<Series<f64>, BlackJackError> {
+        self.column_as_f64(name)
+            .map(Series::from_vec)
+            .ok_or_else(|| {
+                BlackJackError::ValueError(format!("No numeric column named '{}'", name))
+            })
+    }
+
+    /// Positions of the non-NaN values of a numeric column, stably sorted by value
+    fn sorted_positions(
+        &self,
+        column: &str,
+        descending: bool,
+    ) -> Result<Vec<usize>, BlackJackError> {
+        let values = self.numeric_column(column)?;
+        let mut positions = values.positions(|v| !v.is_nan()).collect::<Vec<usize>>();
+        positions.sort_by(|a, b| {
+            let ordering = values[*a].partial_cmp(&values[*b]).unwrap();
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        Ok(positions)
+    }
+
+    /// Add a column computed from existing columns, erroring if its name is taken
+    fn add_derived_column(&mut self, series: Series<f64>) -> Result<(), BlackJackError>
+    where
+        Vec<I>: std::iter::FromIterator<i32>,
+    {
+        let name = series.name().unwrap_or_default();
+        if self.meta.iter().any(|meta| meta.name == name) {
+            return Err(BlackJackError::ValueError(format!(
+                "Column named '{}' already exists",
+                name
+            )));
+        }
+        self.add_column(series)
+    }
+
+    /// Remove all columns from storage, in the order of `meta`
+    fn take_columns(&mut self) -> Vec<GenericSeriesContainer> {
+        let data = &mut self.data;
+        self.meta
+            .iter()
+            .map(|meta| {
+                let column = data.remove(&meta.name).unwrap();
+                match meta.dtype {
+                    DType::F64 => GenericSeriesContainer::F64(*column.downcast().unwrap()),
+                    DType::I64 => GenericSeriesContainer::I64(*column.downcast().unwrap()),
+                    DType::F32 => GenericSeriesContainer::F32(*column.downcast().unwrap()),
+                    DType::I32 => GenericSeriesContainer::I32(*column.downcast().unwrap()),
+                    DType::STRING => GenericSeriesContainer::STRING(*column.downcast().unwrap()),
+                    DType::BOOL => GenericSeriesContainer::BOOL(*column.downcast().unwrap()),
+                }
+            })
+            .collect()
+    }
+
+    /// Build a new frame from the rows at `positions` of columns previously
+    /// removed by `take_columns`
+    fn select_rows(&self, columns: &[GenericSeriesContainer], positions: &[usize]) -> DataFrame<I> {
+        let mut df = DataFrame {
+            index: self.index.select_positions(positions),
+            meta: self.meta.clone(),
+            data: Baggie::new(),
+        };
+        for meta in df.meta.iter_mut() {
+            meta.len = positions.len();
+        }
+        df.restore_columns(
+            columns
+                .par_iter()
+                .map(|column| column.select_positions(positions))
+                .collect(),
+        );
+        df
+    }
+
+    /// Copy the rows at `positions` into a new dataframe, leaving this one untouched
+    fn copy_rows(&self, positions: &[usize]) -> DataFrame<I> {
+        let mut df = DataFrame {
+            index: self.index.select_positions(positions),
+            meta: self.meta.clone(),
+            data: Baggie::new(),
+        };
+        for meta in df.meta.iter_mut() {
+            meta.len = positions.len();
+        }
+        for meta in self.meta.iter() {
+            match meta.dtype {
+                DType::F64 => {
+                    let series: &Series<f64> = self.data.get(&meta.name).unwrap();
+                    df.data
+                        .insert(meta.name.clone(), series.select_positions(positions))
+                }
+                DType::I64 => {
+                    let series: &Series<i64> = self.data.get(&meta.name).unwrap();
+                    df.data
+                        .insert(meta.name.clone(), series.select_positions(positions))
+                }
+                DType::F32 => {
+                    let series: &Series<f32> = self.data.get(&meta.name).unwrap();
+                    df.data
+                        .insert(meta.name.clone(), series.select_positions(positions))
+                }
+                DType::I32 => {
+                    let series: &Series<i32> = self.data.get(&meta.name).unwrap();
+                    df.data
+                        .insert(meta.name.clone(), series.select_positions(positions))
+                }
+                DType::STRING => {
+                    let series: &Series<String> = self.data.get(&meta.name).unwrap();
+                    df.data
+                        .insert(meta.name.clone(), series.select_positions(positions))
+                }
+                DType::BOOL => {
+                    let series: &Series<bool> = self.data.get(&meta.name).unwrap();
+                    df.data
+                        .insert(meta.name.clone(), series.select_positions(positions))
+                }
+            }
+        }
+        df
+    }
+
+    /// Put columns previously removed by `take_columns` back into storage
+    fn restore_columns(&mut self, columns: Vec<GenericSeriesContainer>) {
+        for (meta, column) in self.meta.iter().zip(columns) {
+            match column {
+                GenericSeriesContainer::F64(series) => self.data.insert(meta.name.clone(), series),
+                GenericSeriesContainer::I64(series) => self.data.insert(meta.name.clone(), series),
+                GenericSeriesContainer::F32(series) => self.data.insert(meta.name.clone(), series),
+                GenericSeriesContainer::I32(series) => self.data.insert(meta.name.clone(), series),
+                GenericSeriesContainer::STRING(series) => {
+                    self.data.insert(meta.name.clone(), series)
+                }
+                GenericSeriesContainer::BOOL(series) => self.data.insert(meta.name.clone(), series),
+            }
+        }
+    }
+}
+
+impl DataFrame<i32> {
+    /// Build a `DataFrame` from pairs of column names and series in one call,
+    /// see also the [`dataframe!`] macro. All columns must be of equal length
+    /// and have unique names.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let df = DataFrame::from_columns(vec![
+    ///     ("col1".to_string(), GenericSeriesContainer::from(vec![1, 2, 3])),
+    ///     ("col2".to_string(), GenericSeriesContainer::from(vec![1.5, 2.5, 3.5])),
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(df.len(), 3);
+    /// assert_eq!(df.columns().collect::<Vec<&str>>().len(), 2);
+    /// assert_eq!(df.get_column::<f64>("col2").unwrap().values, vec![1.5, 2.5, 3.5]);
+    /// ```
+    pub fn from_columns(
+        cols: Vec<(String, GenericSeriesContainer)>,
+    ) -> Result<DataFrame<i32>, BlackJackError> {
+        let mut df = DataFrame::new();
+
+        for (name, container) in cols {
+            if df.meta.iter().any(|meta| meta.name == name) {
+                return Err(BlackJackError::ValueError(format!(
+                    "Column '{}' was given more than once",
+                    name
+                )));
+            }
+            match container {
+                GenericSeriesContainer::I64(series) => df.add_named_column(&name, series)?,
+                GenericSeriesContainer::F64(series) => df.add_named_column(&name, series)?,
+                GenericSeriesContainer::I32(series) => df.add_named_column(&name, series)?,
+                GenericSeriesContainer::F32(series) => df.add_named_column(&name, series)?,
+                GenericSeriesContainer::STRING(series) => df.add_named_column(&name, series)?,
+                GenericSeriesContainer::BOOL(series) => df.add_named_column(&name, series)?,
+            }
+        }
+        Ok(df)
+    }
+
+    fn add_named_column<T: BlackJackData + 'static>(
+        &mut self,
+        name: &str,
+        series: Series<T>,
+    ) -> Result<(), BlackJackError> {
+        let mut series = series;
+        series.set_name(name);
+        self.add_column(series)
+    }
 }
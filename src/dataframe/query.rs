@@ -0,0 +1,264 @@
+//! Parsing and evaluation of the expressions used by [`DataFrame::query`]
+//!
+//! The grammar is deliberately small:
+//!
+//! ```text
+//! expr        := conjunction ("or" conjunction)*
+//! conjunction := comparison ("and" comparison)*
+//! comparison  := column operator literal
+//! operator    := ">" | "<" | ">=" | "<=" | "==" | "!="
+//! ```
+//!
+//! Literals which parse as numbers are compared numerically, anything else,
+//! including text within single or double quotes, is compared as a string.
+
+use std::cmp::Ordering;
+
+use crate::prelude::*;
+
+/// Comparison operators supported in an expression
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Operator {
+    Gt,
+    Lt,
+    Ge,
+    Le,
+    Eq,
+    Ne,
+}
+
+impl Operator {
+    fn from_token(token: &str) -> Option<Self> {
+        match token {
+            ">" => Some(Operator::Gt),
+            "<" => Some(Operator::Lt),
+            ">=" => Some(Operator::Ge),
+            "<=" => Some(Operator::Le),
+            "==" => Some(Operator::Eq),
+            "!=" => Some(Operator::Ne),
+            _ => None,
+        }
+    }
+
+    /// Whether `ordering` of a value against a literal satisfies this operator;
+    /// values which can't be compared only satisfy `!=`.
+    fn holds(self, ordering: Option<Ordering>) -> bool {
+        match ordering {
+            Some(ordering) => match self {
+                Operator::Gt => ordering == Ordering::Greater,
+                Operator::Lt => ordering == Ordering::Less,
+                Operator::Ge => ordering != Ordering::Less,
+                Operator::Le => ordering != Ordering::Greater,
+                Operator::Eq => ordering == Ordering::Equal,
+                Operator::Ne => ordering != Ordering::Equal,
+            },
+            None => self == Operator::Ne,
+        }
+    }
+}
+
+/// The right hand side of a comparison
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Literal {
+    Number(f64),
+    Text(String),
+}
+
+/// A parsed expression, evaluated against each [`Row`] of a dataframe
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Expr {
+    Compare(String, Operator, Literal),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Parse `source` into an expression
+    pub(crate) fn parse(source: &str) -> Result<Expr, BlackJackError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser {
+            tokens,
+            position: 0,
+        };
+        let expr = parser.disjunction()?;
+        match parser.next() {
+            None => Ok(expr),
+            Some(token) => Err(BlackJackError::ValueError(format!(
+                "Unexpected '{}' in query",
+                token.text()
+            ))),
+        }
+    }
+
+    /// Names of all columns the expression refers to
+    pub(crate) fn columns(&self) -> Vec<&str> {
+        match self {
+            Expr::Compare(column, _, _) => vec![column.as_str()],
+            Expr::And(lhs, rhs) | Expr::Or(lhs, rhs) => {
+                let mut columns = lhs.columns();
+                columns.extend(rhs.columns());
+                columns
+            }
+        }
+    }
+
+    /// Whether the expression holds for `row`
+    pub(crate) fn eval(&self, row: &Row<'_>) -> bool {
+        match self {
+            Expr::Compare(column, operator, literal) => {
+                let ordering = row.get(column).and_then(|datum| match literal {
+                    Literal::Number(n) => datum.as_f64().and_then(|v| v.partial_cmp(n)),
+                    Literal::Text(text) => match datum {
+                        Datum::STR(_) | Datum::BOOL(_) => Some(String::from(datum).cmp(text)),
+                        _ => None,
+                    },
+                });
+                operator.holds(ordering)
+            }
+            Expr::And(lhs, rhs) => lhs.eval(row) && rhs.eval(row),
+            Expr::Or(lhs, rhs) => lhs.eval(row) || rhs.eval(row),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Operator(String),
+}
+
+impl Token {
+    fn text(&self) -> &str {
+        match self {
+            Token::Word(text) | Token::Quoted(text) | Token::Operator(text) => text,
+        }
+    }
+}
+
+/// Split `source` into words, quoted strings and operators
+fn tokenize(source: &str) -> Result<Vec<Token>, BlackJackError> {
+    let mut tokens = vec![];
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => (),
+            '\'' | '"' => {
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some(next) if next == c => break,
+                        Some(next) => text.push(next),
+                        None => {
+                            return Err(BlackJackError::ValueError(
+                                "Unterminated string in query".to_owned(),
+                            ))
+                        }
+                    }
+                }
+                tokens.push(Token::Quoted(text));
+            }
+            '>' | '<' | '=' | '!' => {
+                let mut op = c.to_string();
+                if chars.peek() == Some(&'=') {
+                    op.push(chars.next().unwrap());
+                }
+                tokens.push(Token::Operator(op));
+            }
+            _ => {
+                let mut word = c.to_string();
+                while let Some(next) = chars.peek() {
+                    if next.is_whitespace() || "'\"<>=!".contains(*next) {
+                        break;
+                    }
+                    word.push(chars.next().unwrap());
+                }
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent parser over the tokens of an expression
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    /// Consume the next token if it's the keyword `keyword`
+    fn keyword(&mut self, keyword: &str) -> bool {
+        match self.tokens.get(self.position) {
+            Some(Token::Word(word)) if word == keyword => {
+                self.position += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn disjunction(&mut self) -> Result<Expr, BlackJackError> {
+        let mut expr = self.conjunction()?;
+        while self.keyword("or") {
+            expr = Expr::Or(Box::new(expr), Box::new(self.conjunction()?));
+        }
+        Ok(expr)
+    }
+
+    fn conjunction(&mut self) -> Result<Expr, BlackJackError> {
+        let mut expr = self.comparison()?;
+        while self.keyword("and") {
+            expr = Expr::And(Box::new(expr), Box::new(self.comparison()?));
+        }
+        Ok(expr)
+    }
+
+    fn comparison(&mut self) -> Result<Expr, BlackJackError> {
+        let column = match self.next() {
+            Some(Token::Word(word)) | Some(Token::Quoted(word)) => word,
+            Some(token) => {
+                return Err(BlackJackError::ValueError(format!(
+                    "Expected a column name in query, found '{}'",
+                    token.text()
+                )))
+            }
+            None => {
+                return Err(BlackJackError::ValueError(
+                    "Expected a column name at end of query".to_owned(),
+                ))
+            }
+        };
+        let operator = match self.next() {
+            Some(Token::Operator(op)) => Operator::from_token(&op).ok_or_else(|| {
+                BlackJackError::ValueError(format!("Unknown operator '{}' in query", op))
+            })?,
+            _ => {
+                return Err(BlackJackError::ValueError(format!(
+                    "Expected an operator after '{}' in query",
+                    column
+                )))
+            }
+        };
+        let literal = match self.next() {
+            Some(Token::Word(word)) => match word.parse::<f64>() {
+                Ok(n) => Literal::Number(n),
+                Err(_) => Literal::Text(word),
+            },
+            Some(Token::Quoted(text)) => Literal::Text(text),
+            _ => {
+                return Err(BlackJackError::ValueError(format!(
+                    "Expected a value to compare '{}' against in query",
+                    column
+                )))
+            }
+        };
+        Ok(Expr::Compare(column, operator, literal))
+    }
+}
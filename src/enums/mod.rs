@@ -19,6 +19,9 @@ pub enum DType {
 
     /// `String`
     STRING,
+
+    /// `bool`
+    BOOL,
 }
 
 /// Container for use with `Row` struct
@@ -38,6 +41,88 @@ pub enum Datum<'a> {
 
     /// Refrence to a String within the dataframe
     STR(&'a String),
+
+    /// Refrence to a bool within the dataframe
+    BOOL(&'a bool),
+}
+
+impl<'a> Datum<'a> {
+    /// Whether this is a numeric value greater than `n`; always `false`
+    /// for `STR` and `BOOL`.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut df = DataFrame::new();
+    /// let mut x = Series::from_vec(vec![1, 2, 3, 4]);
+    /// x.set_name("x");
+    /// df.add_column(x).unwrap();
+    ///
+    /// df.filter_by_row(|row| row["x"].gt(2.0));
+    /// assert_eq!(df.len(), 2);
+    /// ```
+    pub fn gt(&self, n: f64) -> bool {
+        self.as_f64().is_some_and(|val| val > n)
+    }
+
+    /// Whether this is a numeric value less than `n`; always `false`
+    /// for `STR` and `BOOL`.
+    pub fn lt(&self, n: f64) -> bool {
+        self.as_f64().is_some_and(|val| val < n)
+    }
+
+    /// Whether this is a numeric value equal to `n`; always `false`
+    /// for `STR` and `BOOL`.
+    pub fn eq_num(&self, n: f64) -> bool {
+        self.as_f64().is_some_and(|val| val == n)
+    }
+
+    /// Cast numeric variants to `f64`
+    pub(crate) fn as_f64(&self) -> Option<f64> {
+        match self {
+            Datum::F64(val) => Some(**val),
+            Datum::I64(val) => Some(**val as f64),
+            Datum::F32(val) => Some(f64::from(**val)),
+            Datum::I32(val) => Some(f64::from(**val)),
+            Datum::STR(_) | Datum::BOOL(_) => None,
+        }
+    }
+}
+
+impl<'a> From<&Datum<'a>> for String {
+    fn from(datum: &Datum<'a>) -> String {
+        match datum {
+            Datum::F64(val) => val.to_string(),
+            Datum::I64(val) => val.to_string(),
+            Datum::F32(val) => val.to_string(),
+            Datum::I32(val) => val.to_string(),
+            Datum::STR(val) => val.to_string(),
+            Datum::BOOL(val) => val.to_string(),
+        }
+    }
+}
+
+/// Owned counterpart of [`Datum`], for use with the `OwnedRow` struct
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataElement {
+    /// An owned f64
+    F64(f64),
+
+    /// An owned i64
+    I64(i64),
+
+    /// An owned f32
+    F32(f32),
+
+    /// An owned i32
+    I32(i32),
+
+    /// An owned String
+    STR(String),
+
+    /// An owned bool
+    BOOL(bool),
 }
 
 /// An enum representation of a `Series`, typically only seen
@@ -58,4 +143,18 @@ pub enum Column {
 
     /// A column in the `DataFrame` of type `Series<String>`
     STR(Series<String>),
+
+    /// A column in the `DataFrame` of type `Series<bool>`
+    BOOL(Series<bool>),
+}
+
+/// Whether [`DataFrame::dropna`] drops rows with _any_ or only with _all_
+/// of their float values missing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DropHow {
+    /// Drop a row if any of its float values is NaN
+    Any,
+
+    /// Drop a row only if all of its float values are NaN
+    All,
 }
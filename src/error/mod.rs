@@ -28,6 +28,11 @@ pub enum BlackJackError {
     /// Length mismatch
     #[fail(display = "LengthMismatch")]
     LengthMismatch(String),
+
+    /// Failure reading or writing a Parquet file
+    #[cfg(feature = "parquet")]
+    #[fail(display = "Parquet error")]
+    ParquetError(parquet::errors::ParquetError),
 }
 
 impl From<&str> for BlackJackError {
@@ -53,3 +58,17 @@ impl From<Box<bincode::ErrorKind>> for BlackJackError {
         BlackJackError::SerializationDecodeError(error)
     }
 }
+
+#[cfg(feature = "parquet")]
+impl From<parquet::errors::ParquetError> for BlackJackError {
+    fn from(error: parquet::errors::ParquetError) -> BlackJackError {
+        BlackJackError::ParquetError(error)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<arrow::error::ArrowError> for BlackJackError {
+    fn from(error: arrow::error::ArrowError) -> BlackJackError {
+        BlackJackError::ParquetError(error.into())
+    }
+}
@@ -29,6 +29,26 @@ where
     Some(var.sqrt())
 }
 
+/// Calculate the covariance of two equally long slices, where `ddof` is
+/// either 0_f64 or 1_f64 for population or sample covariance.
+pub fn covariance<A, B>(a: &[A], b: &[B], ddof: f64) -> Option<f64>
+where
+    A: ToPrimitive,
+    B: ToPrimitive,
+{
+    if a.is_empty() || a.len() != b.len() {
+        return None;
+    }
+    let mean_a = a.iter().map(|v| v.to_f64().unwrap()).sum::<f64>() / a.len() as f64;
+    let mean_b = b.iter().map(|v| v.to_f64().unwrap()).sum::<f64>() / b.len() as f64;
+    let numerator = a
+        .iter()
+        .zip(b)
+        .map(|(x, y)| (x.to_f64().unwrap() - mean_a) * (y.to_f64().unwrap() - mean_b))
+        .sum::<f64>();
+    Some(numerator / (a.len() as f64 - ddof))
+}
+
 /// Calculate mean / average
 pub fn mean<T>(values: &[T]) -> Option<f64>
 where
@@ -45,28 +65,103 @@ where
     values.iter().map(|v| *v).sum()
 }
 
-/// Calculate min
+/// Number of independent accumulators used by the float sums, allowing
+/// the compiler to vectorize the inner loop.
+const SUM_LANES: usize = 8;
+
+/// Calculate sum of `f64`s, processing the slice in chunks to encourage SIMD.
+pub fn sum_f64(values: &[f64]) -> f64 {
+    let mut lanes = [0_f64; SUM_LANES];
+    let chunks = values.chunks_exact(SUM_LANES);
+    let remainder = chunks.remainder().iter().sum::<f64>();
+    for chunk in chunks {
+        for (lane, value) in lanes.iter_mut().zip(chunk) {
+            *lane += value;
+        }
+    }
+    lanes.iter().sum::<f64>() + remainder
+}
+
+/// Calculate sum of `f32`s, processing the slice in chunks to encourage SIMD.
+pub fn sum_f32(values: &[f32]) -> f32 {
+    let mut lanes = [0_f32; SUM_LANES];
+    let chunks = values.chunks_exact(SUM_LANES);
+    let remainder = chunks.remainder().iter().sum::<f32>();
+    for chunk in chunks {
+        for (lane, value) in lanes.iter_mut().zip(chunk) {
+            *lane += value;
+        }
+    }
+    lanes.iter().sum::<f32>() + remainder
+}
+
+/// Calculate min, skipping NaN values. If several elements are equally minimum,
+/// the first is returned. Returns `None` if empty or all values are NaN.
 pub fn min<T>(values: &[T]) -> Option<&T>
 where
     T: Num + PartialOrd + Copy,
 {
-    values.iter().min_by(|a, b| match a.partial_cmp(b) {
-        Some(Ordering::Less) => Ordering::Less,
-        Some(Ordering::Greater) => Ordering::Greater,
-        Some(Ordering::Equal) => Ordering::Equal,
-        None => Ordering::Equal,
-    })
+    values
+        .iter()
+        .filter(|v| !is_nan(*v))
+        .min_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
 }
 
-/// Calculate max
+/// Calculate max, skipping NaN values. If several elements are equally maximum,
+/// the first is returned. Returns `None` if empty or all values are NaN.
 pub fn max<T>(values: &[T]) -> Option<&T>
 where
     T: Num + PartialOrd + Copy,
 {
-    values.iter().min_by(|a, b| match b.partial_cmp(a) {
-        Some(Ordering::Less) => Ordering::Less,
-        Some(Ordering::Greater) => Ordering::Greater,
-        Some(Ordering::Equal) => Ordering::Equal,
-        None => Ordering::Equal,
-    })
+    values
+        .iter()
+        .filter(|v| !is_nan(*v))
+        .min_by(|a, b| b.partial_cmp(a).unwrap_or(Ordering::Equal))
+}
+
+/// Calculate the median without fully sorting, values will be reordered in place.
+pub fn median_unsorted<T>(values: &mut [T]) -> Option<f64>
+where
+    T: PartialOrd + Copy + ToPrimitive,
+{
+    if values.is_empty() {
+        return None;
+    }
+    let cmp = |a: &T, b: &T| a.partial_cmp(b).unwrap_or(Ordering::Equal);
+    let is_odd = values.len() % 2 == 1;
+    let mid = values.len() / 2;
+    let (lower, upper, _) = values.select_nth_unstable_by(mid, cmp);
+
+    if is_odd {
+        upper.to_f64()
+    } else {
+        // The other middle value is the largest of the lower partition
+        let lower = lower.iter().max_by(|a, b| cmp(a, b))?;
+        Some((lower.to_f64()? + upper.to_f64()?) / 2.)
+    }
+}
+
+/// Positions `0..len` in a random order, using a Fisher-Yates shuffle driven
+/// by a SplitMix64 generator; the same `seed` always gives the same order.
+pub fn shuffled_positions(len: usize, seed: u64) -> Vec<usize> {
+    let mut state = seed;
+    let mut next = || {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    };
+
+    let mut positions = (0..len).collect::<Vec<usize>>();
+    for i in (1..len).rev() {
+        let j = (next() % (i as u64 + 1)) as usize;
+        positions.swap(i, j);
+    }
+    positions
+}
+
+/// NaN is the only value which isn't comparable to itself
+fn is_nan<T: PartialOrd>(value: &T) -> bool {
+    value.partial_cmp(value).is_none()
 }
@@ -72,3 +72,49 @@ macro_rules! impl_series_by_series_op {
 
     }
 }
+
+/// Implement conversion of a `Series` or `Vec` of a dtype into a `GenericSeriesContainer`
+#[macro_export]
+macro_rules! impl_generic_series_container_from {
+    // Use: impl_generic_series_container_from!(f64, F64)
+    ($primitive:ty, $variant:ident) => {
+        impl From<Series<$primitive>> for GenericSeriesContainer {
+            fn from(series: Series<$primitive>) -> Self {
+                GenericSeriesContainer::$variant(series)
+            }
+        }
+
+        impl From<Vec<$primitive>> for GenericSeriesContainer {
+            fn from(vec: Vec<$primitive>) -> Self {
+                GenericSeriesContainer::$variant(Series::from_vec(vec))
+            }
+        }
+    };
+}
+
+/// Build a `DataFrame<i32>` from pairs of column names and values, any
+/// `Vec` or `Series` of a supported dtype can be given as the values.
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate blackjack;
+/// use blackjack::prelude::*;
+///
+/// # fn main() {
+/// let df = dataframe![
+///     "col1" => vec![1, 2, 3],
+///     "col2" => vec![1.5, 2.5, 3.5]
+/// ].unwrap();
+///
+/// assert_eq!(df.len(), 3);
+/// assert_eq!(df.n_columns(), 2);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! dataframe {
+    ($($name:expr => $values:expr),* $(,)?) => {
+        $crate::dataframe::DataFrame::from_columns(vec![
+            $(($name.to_string(), $crate::series::GenericSeriesContainer::from($values))),*
+        ])
+    };
+}
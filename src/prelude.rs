@@ -1,4 +1,7 @@
 //! Default and recommended imports for functionality of crate.
+//!
+//! Includes every type returned from the public API, ie. [`GenericSeriesContainer`],
+//! [`Column`], [`Datum`], [`Row`], [`Element`], [`SeriesMeta`] and [`DType`].
 
 pub use crate::dataframe::*;
 pub use crate::enums::*;
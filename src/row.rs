@@ -1,6 +1,7 @@
 //!
 //! Representation of a row in a `DataFrame` and related structs
 
+use std::fmt;
 use std::ops::Index;
 
 use crate::prelude::*;
@@ -22,6 +23,14 @@ impl<'a> Row<'a> {
     pub fn add(&mut self, data: Element<'a>) {
         self.data.push(data)
     }
+
+    /// Get the data of the `Element` belonging to a column name, if it exists
+    pub fn get(&self, name: &str) -> Option<&Datum<'a>> {
+        self.data
+            .iter()
+            .find(|element| element.name == name)
+            .map(|element| &element.data)
+    }
 }
 
 /// Represent a single data element, the enum of the data itself, and the name
@@ -39,6 +48,30 @@ impl<'a> Element<'a> {
     pub fn new(name: String, data: Datum<'a>) -> Self {
         Element { name, data }
     }
+
+    /// The value of this element formatted as a `String`
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let element = Element::new("a".to_string(), Datum::I32(&1));
+    /// assert_eq!(element.value_as_string(), "1".to_string());
+    /// ```
+    pub fn value_as_string(&self) -> String {
+        String::from(&self.data)
+    }
+}
+
+impl<'a> fmt::Display for Row<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elements = self
+            .data
+            .iter()
+            .map(|element| format!("{}={}", element.name, element.value_as_string()))
+            .collect::<Vec<String>>();
+        write!(f, "{}", elements.join(", "))
+    }
 }
 
 impl<'a, 'b> Index<&'b str> for Row<'a> {
@@ -52,3 +85,35 @@ impl<'a, 'b> Index<&'b str> for Row<'a> {
         panic!("Element named: {} now found", name);
     }
 }
+
+/// Representation of a DataFrame row which owns its elements, allowing it
+/// to outlive whatever it was created from.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct OwnedRow {
+    /// Represents the elements in the `OwnedRow` as pairs of column name and value
+    pub data: Vec<(String, DataElement)>,
+}
+
+impl OwnedRow {
+    /// Create an empty `OwnedRow`
+    pub fn new() -> Self {
+        OwnedRow { data: vec![] }
+    }
+
+    /// Push a named `DataElement` into the `OwnedRow`
+    pub fn add(&mut self, name: String, data: DataElement) {
+        self.data.push((name, data))
+    }
+}
+
+impl Index<&str> for OwnedRow {
+    type Output = DataElement;
+    fn index(&self, name: &str) -> &Self::Output {
+        for (element_name, data) in &self.data {
+            if element_name == name {
+                return data;
+            }
+        }
+        panic!("Element named: {} now found", name);
+    }
+}
@@ -16,8 +16,10 @@
 //! assert_eq!(series.len(), 5);
 //! ```
 
+use std::collections::{HashMap, HashSet};
 use std::convert::From;
 use std::fmt;
+use std::hash::Hash;
 use std::iter::{FromIterator, Sum};
 use std::marker::{Send, Sync};
 use std::ops::{Index, IndexMut, Range};
@@ -34,10 +36,12 @@ use stats;
 pub mod overloaders;
 pub mod rolling;
 pub mod series_groupby;
+pub mod validation;
 pub mod variants;
 
 pub use self::rolling::*;
 pub use self::series_groupby::*;
+pub use self::validation::*;
 pub use self::variants::*;
 
 use crate::funcs;
@@ -49,6 +53,30 @@ impl_series_into_iter!(f64);
 impl_series_into_iter!(i64);
 impl_series_into_iter!(f32);
 impl_series_into_iter!(i32);
+impl_series_into_iter!(bool);
+
+/// Number of positions above which [`Series::iloc_par`] indexes in parallel.
+pub const ILOC_PAR_THRESHOLD: usize = 10_000_000;
+
+/// Length above which [`Series::unique`] sorts its values in parallel.
+pub const UNIQUE_PAR_THRESHOLD: usize = 100_000;
+
+/// Length below which [`Series::map_par`] maps in a single thread.
+pub const MAP_PAR_THRESHOLD: usize = 10_000;
+
+/// Length below which [`Series::par_sum`] and [`Series::par_mean`] reduce in a single thread.
+pub const PAR_REDUCE_THRESHOLD: usize = 100_000;
+
+/// Ensure all positions are within the bounds of a container of length `len`
+pub(crate) fn check_positions(positions: &[usize], len: usize) -> Result<(), BlackJackError> {
+    match positions.iter().find(|position| **position >= len) {
+        Some(position) => Err(BlackJackError::ValueError(format!(
+            "Position {} is out of bounds for length {}",
+            position, len
+        ))),
+        None => Ok(()),
+    }
+}
 
 /// Series struct for containing underlying Array and other meta data.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, PartialOrd)]
@@ -64,6 +92,9 @@ where
     pub values: Vec<T>,
 
     dtype: Option<DType>,
+
+    /// Optional index labels of the series, when not set the labels are the positions.
+    index: Option<Vec<i64>>,
 }
 
 impl<I> Default for Series<I>
@@ -100,6 +131,7 @@ where
             name: None,
             dtype,
             values,
+            index: None,
         }
     }
 
@@ -125,6 +157,189 @@ where
                 }
             })
             .collect::<Vec<T>>();
+
+        if let Some(index) = self.index.as_mut() {
+            *index = index
+                .iter()
+                .enumerate()
+                .filter(|(position, _)| !positions.contains(position))
+                .map(|(_, label)| *label)
+                .collect();
+        }
+    }
+
+    /// Drop index _labels_ of the Series, see [`Series::set_index`]. When no index
+    /// has been set, the labels are the positions of the values.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::from_vec(vec![1, 2, 3, 4]);
+    /// series.set_index(vec![10, 20, 30, 40]).unwrap();
+    ///
+    /// series.drop_indexes(vec![20, 40]);
+    /// assert_eq!(series.values, vec![1, 3]);
+    /// assert_eq!(series.index(), Some(&[10, 30][..]));
+    /// ```
+    pub fn drop_indexes<I>(&mut self, labels: I)
+    where
+        I: IntoIterator<Item = i64>,
+    {
+        let labels = labels.into_iter().collect::<HashSet<i64>>();
+        let positions = match &self.index {
+            Some(index) => index
+                .iter()
+                .enumerate()
+                .filter(|(_, label)| labels.contains(label))
+                .map(|(position, _)| position)
+                .collect::<Vec<usize>>(),
+            None => (0..self.len())
+                .filter(|position| labels.contains(&(*position as i64)))
+                .collect::<Vec<usize>>(),
+        };
+        self.drop_positions(positions);
+    }
+
+    /// Set the index labels of the Series, which must be of equal length to the series.
+    ///
+    /// Labels are `i64`s only, unlike the index of a [`DataFrame`]; until an index
+    /// is set, [`Series::index`] is `None` and the labels are the positions.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::from_vec(vec![1, 2, 3]);
+    /// assert_eq!(series.index(), None);
+    ///
+    /// assert!(series.set_index(vec![0, 1]).is_err());
+    /// assert!(series.set_index(vec![5, 6, 7]).is_ok());
+    /// assert_eq!(series.index(), Some(&[5, 6, 7][..]));
+    /// ```
+    pub fn set_index<Idx>(&mut self, index: Idx) -> Result<(), BlackJackError>
+    where
+        Idx: IntoIterator<Item = i64>,
+    {
+        let index = index.into_iter().collect::<Vec<i64>>();
+        if index.len() != self.len() {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "Series has length: {}, cannot set index of length: {}",
+                self.len(),
+                index.len()
+            )));
+        }
+        self.index = Some(index);
+        Ok(())
+    }
+
+    /// Get the index labels of the Series, `None` if no index has been set in which
+    /// case the labels are the positions of the values.
+    pub fn index(&self) -> Option<&[i64]> {
+        self.index.as_deref()
+    }
+
+    /// Remove the index labels of the Series, reverting to positional labels.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::from_vec(vec![1, 2, 3]);
+    /// series.set_index(vec![5, 6, 7]).unwrap();
+    ///
+    /// series.reset_index();
+    /// assert_eq!(series.index(), None);
+    /// ```
+    pub fn reset_index(&mut self) {
+        self.index = None;
+    }
+
+    /// Conform the Series to a new index, values follow the order of `new_index`
+    /// and labels not present in the current index are set to `fill`.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::from_vec(vec![1., 2., 3.]);
+    /// series.set_index(vec![10, 20, 30]).unwrap();
+    ///
+    /// let reindexed = series.reindex(&[30, 40, 10], f64::NAN);
+    /// assert_eq!(reindexed[0], 3.);
+    /// assert!(reindexed[1].is_nan());
+    /// assert_eq!(reindexed[2], 1.);
+    /// assert_eq!(reindexed.index(), Some(&[30, 40, 10][..]));
+    /// ```
+    pub fn reindex(&self, new_index: &[i64], fill: T) -> Series<T> {
+        let positions = self.label_positions();
+        let mut series = Series::from_vec(
+            new_index
+                .iter()
+                .map(|label| match positions.get(label) {
+                    Some(position) => self.values[*position].clone(),
+                    None => fill.clone(),
+                })
+                .collect(),
+        );
+        series.name = self.name.clone();
+        series.index = Some(new_index.to_vec());
+        series
+    }
+
+    /// Map each index label to the position of its first occurrence
+    fn label_positions(&self) -> HashMap<i64, usize> {
+        let mut positions = HashMap::new();
+        match &self.index {
+            Some(index) => {
+                for (position, label) in index.iter().enumerate() {
+                    positions.entry(*label).or_insert(position);
+                }
+            }
+            None => {
+                for position in 0..self.len() {
+                    positions.insert(position as i64, position);
+                }
+            }
+        }
+        positions
+    }
+
+    /// Add labels for newly added values, continuing from the largest label
+    fn extend_index(&mut self) {
+        let len = self.len();
+        if let Some(index) = self.index.as_mut() {
+            let mut next = index.iter().max().map_or(0, |max| max + 1);
+            while index.len() < len {
+                index.push(next);
+                next += 1;
+            }
+        }
+    }
+
+    /// Drop positions of the Series, returning an error without modifying the
+    /// series if any position is out of bounds.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::arange(0, 5);
+    ///
+    /// assert!(series.try_drop_positions(vec![0, 5]).is_err());
+    /// assert_eq!(series.len(), 5);
+    ///
+    /// assert!(series.try_drop_positions(vec![0, 4]).is_ok());
+    /// assert_eq!(series.values, vec![1, 2, 3]);
+    /// ```
+    pub fn try_drop_positions<I>(&mut self, positions: I) -> Result<(), BlackJackError>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let positions = positions.into_iter().collect::<Vec<usize>>();
+        check_positions(&positions, self.len())?;
+        self.drop_positions(positions);
+        Ok(())
     }
 
     /// Fetch values from the series by matching index _positions_, _not_ by index value.
@@ -153,6 +368,121 @@ where
             .collect::<Vec<&T>>()
     }
 
+    /// Build a new series from the values at `positions`, keeping the name and
+    /// the index labels of the selected values.
+    pub(crate) fn select_positions(&self, positions: &[usize]) -> Series<T> {
+        let mut series = Series::from_vec(
+            positions
+                .iter()
+                .map(|position| self.values[*position].clone())
+                .collect(),
+        );
+        series.name = self.name.clone();
+        series.dtype = self.dtype.clone();
+        series.index = self
+            .index
+            .as_ref()
+            .map(|index| positions.iter().map(|position| index[*position]).collect());
+        series
+    }
+
+    /// Fetch _owned_ values from the series by matching index _positions_, see [`Series::iloc`].
+    ///
+    /// Indexing is done in parallel when the number of positions exceeds [`ILOC_PAR_THRESHOLD`],
+    /// otherwise it falls back to single threaded execution.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::arange(0, 10000);
+    ///
+    /// let vals = series.iloc_par(&[250, 500, 1000]);
+    /// assert_eq!(vals, vec![250, 500, 1000]);
+    /// ```
+    pub fn iloc_par(&self, idx_vals: &[usize]) -> Vec<T>
+    where
+        T: Send + Sync,
+    {
+        self.iloc_par_with_threshold(idx_vals, ILOC_PAR_THRESHOLD)
+    }
+
+    /// Same as [`Series::iloc_par`], indexing in parallel when the number of
+    /// positions exceeds the given `threshold`.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::arange(0, 10000);
+    ///
+    /// // Always go parallel
+    /// let vals = series.iloc_par_with_threshold(&[250, 500, 1000], 0);
+    /// assert_eq!(vals, vec![250, 500, 1000]);
+    /// ```
+    pub fn iloc_par_with_threshold(&self, idx_vals: &[usize], threshold: usize) -> Vec<T>
+    where
+        T: Send + Sync,
+    {
+        if idx_vals.len() > threshold {
+            idx_vals
+                .par_iter()
+                .map(|idx_val| self.values[*idx_val].clone())
+                .collect()
+        } else {
+            idx_vals
+                .iter()
+                .map(|idx_val| self.values[*idx_val].clone())
+                .collect()
+        }
+    }
+
+    /// Fetch the value `n` positions from the end of the series, where `get_back(0)`
+    /// is the last value. Returns `None` if `n` is beyond the start of the series.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::arange(0, 5);
+    ///
+    /// assert_eq!(series.get_back(0), Some(&4));
+    /// assert_eq!(series.get_back(1), Some(&3));
+    /// assert_eq!(series.get_back(5), None);
+    /// ```
+    pub fn get_back(&self, n: usize) -> Option<&T> {
+        if n < self.len() {
+            self.values.get(self.len() - 1 - n)
+        } else {
+            None
+        }
+    }
+
+    /// Fetch the value whose index _label_ matches `label`, see [`Series::set_index`].
+    /// If the label occurs more than once, the first match is returned. When no index
+    /// has been set, the labels are the positions of the values.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::from_vec(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+    /// series.set_index(vec![30, 10, 20]).unwrap();
+    ///
+    /// assert_eq!(series.loc(10), Some(&"b".to_string()));
+    /// assert_eq!(series.loc(0), None);
+    /// ```
+    pub fn loc(&self, label: i64) -> Option<&T> {
+        match &self.index {
+            Some(index) => index
+                .iter()
+                .position(|l| *l == label)
+                .and_then(|position| self.values.get(position)),
+            None if label >= 0 => self.values.get(label as usize),
+            None => None,
+        }
+    }
+
     /// Calculate a predefined rolling aggregation
     ///
     /// See [`Rolling`] for additional functionality.
@@ -181,6 +511,118 @@ where
         Rolling::new(window, &self)
     }
 
+    /// Iterate over all contiguous windows of length `size` as raw slices. Unlike
+    /// [`Series::rolling`], no NaN padding is produced for the leading positions;
+    /// if the series is shorter than `size`, no windows are yielded.
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::arange(0, 4);
+    /// let windows = series.windows(2).collect::<Vec<&[i32]>>();
+    ///
+    /// assert_eq!(windows, vec![&[0, 1], &[1, 2], &[2, 3]]);
+    /// ```
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = &[T]> {
+        self.values.windows(size)
+    }
+
+    /// Iterate over the series in non-overlapping chunks of `size` values, for
+    /// processing in batches. The final chunk may be shorter than `size` when the
+    /// length of the series isn't evenly divisible by it.
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::arange(0, 10);
+    /// let chunks = series.chunks(3).collect::<Vec<&[i32]>>();
+    ///
+    /// assert_eq!(chunks.len(), 4);
+    /// assert_eq!(chunks[3], &[9]);
+    /// ```
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = &[T]> {
+        self.values.chunks(size)
+    }
+
+    /// Shift values by `periods` positions, filling vacated positions with `fill`.
+    /// Positive periods shift values forward, negative periods shift them backward.
+    /// The name and index of the series are kept.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1, 2, 3, 4]);
+    ///
+    /// assert_eq!(series.shift_fill(1, 0).values, vec![0, 1, 2, 3]);
+    /// assert_eq!(series.shift_fill(-2, 0).values, vec![3, 4, 0, 0]);
+    /// ```
+    pub fn shift_fill(&self, periods: i64, fill: T) -> Series<T> {
+        let len = self.len();
+        let n_fill = (periods.unsigned_abs() as usize).min(len);
+        let fills = std::iter::repeat_n(fill, n_fill);
+
+        let values = if periods >= 0 {
+            fills
+                .chain(self.values[..len - n_fill].iter().cloned())
+                .collect::<Vec<T>>()
+        } else {
+            self.values[n_fill..]
+                .iter()
+                .cloned()
+                .chain(fills)
+                .collect::<Vec<T>>()
+        };
+
+        let mut series = Series::from_vec(values);
+        series.name = self.name.clone();
+        series.index = self.index.clone();
+        series
+    }
+
+    /// Difference between each value and the value `periods` positions before it,
+    /// as `f64`s. The first `periods` values have nothing to compare with and are `NaN`.
+    /// The name and index of the series are kept.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1, 3, 6, 10]);
+    /// let diff = series.diff(1);
+    ///
+    /// assert!(diff[0].is_nan());
+    /// assert_eq!(diff[1..4].to_vec(), vec![2., 3., 4.]);
+    /// ```
+    pub fn diff(&self, periods: usize) -> Series<f64>
+    where
+        T: ToPrimitive,
+    {
+        let values = self
+            .values
+            .iter()
+            .enumerate()
+            .map(|(position, value)| match position.checked_sub(periods) {
+                Some(previous) => {
+                    value.to_f64().unwrap_or(f64::NAN)
+                        - self.values[previous].to_f64().unwrap_or(f64::NAN)
+                }
+                None => f64::NAN,
+            })
+            .collect::<Vec<f64>>();
+
+        let mut series = Series::from_vec(values);
+        series.name = self.name.clone();
+        series.index = self.index.clone();
+        series
+    }
+
     /// Return an iterable of booleans determining if any element is NaN
     ///
     /// ## Example
@@ -205,6 +647,65 @@ where
         self.values.iter().map(|v| v.is_nan())
     }
 
+    /// Positions of the NaN values, complementing [`Series::isna`]
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1., f64::NAN, 3., f64::NAN]);
+    /// assert_eq!(series.nan_positions(), vec![1, 3]);
+    /// ```
+    pub fn nan_positions(&self) -> Vec<usize>
+    where
+        T: Float,
+    {
+        self.positions(|v| v.is_nan()).collect()
+    }
+
+    /// Replace NaN values in place with `value`
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::from_vec(vec![1., f64::NAN, 3.]);
+    /// series.fillna(0.);
+    ///
+    /// assert_eq!(series.values, vec![1., 0., 3.]);
+    /// ```
+    pub fn fillna(&mut self, value: T)
+    where
+        T: Float,
+    {
+        self.values
+            .iter_mut()
+            .filter(|v| v.is_nan())
+            .for_each(|v| *v = value);
+    }
+
+    /// Transform in place, with `func`, only the elements meeting `condition`
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::from_vec(vec![-1, 2, -3]);
+    /// series.apply_where(|x| *x < 0, |x| *x = -*x);
+    ///
+    /// assert_eq!(series.values, vec![1, 2, 3]);
+    /// ```
+    pub fn apply_where<P, F>(&mut self, condition: P, func: F)
+    where
+        P: Fn(&T) -> bool,
+        F: Fn(&mut T),
+    {
+        self.values
+            .iter_mut()
+            .filter(|v| condition(v))
+            .for_each(func);
+    }
+
     /// Determine if _all_ elements in the Series meet a given condition
     ///
     /// This will stop iteration after encountering the first element which breaks
@@ -242,6 +743,26 @@ where
         self.values.iter().all_equal()
     }
 
+    /// Compare only the values of two series, ignoring their names
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut s1 = Series::from_vec(vec![1, 2, 3]);
+    /// let s2 = Series::from_vec(vec![1, 2, 3]);
+    /// s1.set_name("s1");
+    ///
+    /// assert!(s1.values_eq(&s2));
+    /// assert!(s1 != s2);
+    /// ```
+    pub fn values_eq(&self, other: &Series<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.values == other.values
+    }
+
     /// Determine if _any_ element in the Series meets a given condition
     ///
     /// This will stop iteration after encountering the first element which meets
@@ -315,6 +836,9 @@ where
     /// Function takes some type `T` and returns some type `B` which
     /// has `BlackJackData` implemented.
     ///
+    /// Series shorter than [`MAP_PAR_THRESHOLD`] are mapped in a single thread,
+    /// where the overhead of going parallel would outweigh the gain.
+    ///
     /// ## Example
     ///
     /// ```
@@ -330,10 +854,47 @@ where
         B: BlackJackData,
         F: Fn(T) -> B + Send + Sync,
     {
+        if self.len() < MAP_PAR_THRESHOLD {
+            return self.map(func);
+        }
         let new_data = self.values.into_par_iter().map(func).collect();
         Series::from_vec(new_data)
     }
 
+    /// Map a function over a series _in parallel_ one chunk of `chunk` values at
+    /// a time, bounding how many values are in flight for functions which
+    /// allocate per value.
+    ///
+    /// Panics if `chunk` is 0.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::arange(0, 10);
+    ///
+    /// let new_series = series.map_par_chunked(3, |x| x * 2);
+    /// assert_eq!(new_series.values, (0..10).map(|x| x * 2).collect::<Vec<i32>>());
+    /// ```
+    pub fn map_par_chunked<B, F>(self, chunk: usize, func: F) -> Series<B>
+    where
+        B: BlackJackData,
+        F: Fn(T) -> B + Send + Sync,
+    {
+        assert!(chunk > 0, "chunk size must be non-zero");
+        let mut new_data = Vec::with_capacity(self.len());
+        let mut values = self.values.into_iter();
+        loop {
+            let batch = values.by_ref().take(chunk).collect::<Vec<T>>();
+            if batch.is_empty() {
+                break;
+            }
+            new_data.par_extend(batch.into_par_iter().map(&func));
+        }
+        Series::from_vec(new_data)
+    }
+
     /// Map a function over a series in a single thread
     /// Function takes some type `T` and returns some type `B` which
     /// has `BlackJackData` implemented.
@@ -346,6 +907,43 @@ where
         Series::from_vec(new_data)
     }
 
+    /// Replace each value with its entry in `table`, keeping the name and index.
+    /// Returns an error if any value has no entry.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec!["lo".to_string(), "hi".to_string(), "lo".to_string()]);
+    ///
+    /// let mut table = HashMap::new();
+    /// table.insert("lo".to_string(), 0);
+    /// table.insert("hi".to_string(), 1);
+    ///
+    /// assert_eq!(series.map_lookup(&table).unwrap().values, vec![0, 1, 0]);
+    /// ```
+    pub fn map_lookup<B>(&self, table: &HashMap<T, B>) -> Result<Series<B>, BlackJackError>
+    where
+        T: Hash + Eq,
+        B: BlackJackData,
+    {
+        let values = self
+            .values
+            .iter()
+            .map(|value| {
+                table.get(value).cloned().ok_or_else(|| {
+                    BlackJackError::ValueError(format!("No mapping for value: {}", value))
+                })
+            })
+            .collect::<Result<Vec<B>, BlackJackError>>()?;
+
+        let mut series = Series::from_vec(values);
+        series.name = self.name.clone();
+        series.index = self.index.clone();
+        Ok(series)
+    }
+
     /// Convert the series into another [`DType`] (creates a new series)
     ///
     /// ## Example
@@ -370,8 +968,9 @@ where
             .collect::<Result<Vec<A>, _>>()?;
         let series = Series {
             name: self.name.clone(),
-            dtype: Some(values[0].dtype()),
+            dtype: values.first().map(|v| v.dtype()),
             values,
+            index: self.index.clone(),
         };
         Ok(series)
     }
@@ -398,13 +997,59 @@ where
             .map(|v| v.parse::<A>().map_err(|_| "Cannot cast into type"))
             .collect::<Result<Vec<A>, _>>()?;
         let series = Series {
-            name: self.name.clone(),
+            name: self.name,
             dtype: Some(values[0].dtype()),
             values,
+            index: self.index,
         };
         Ok(series)
     }
 
+    /// Convert the series into another [`DType`], casting what it can instead of
+    /// failing the whole conversion on the first unparseable value.
+    ///
+    /// Returns the new series along with the _positions_ which failed to cast, these
+    /// should be treated as missing. Float targets hold `NaN` at those positions, other
+    /// types hold their `Default` value.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec!["1".to_string(), "junk".to_string(), "3".to_string()]);
+    /// let (casted, failed) = series.try_astype::<f64>();
+    ///
+    /// assert_eq!(failed, vec![1]);
+    /// assert!(casted[1].is_nan());
+    /// assert_eq!(casted[2], 3.0);
+    /// ```
+    pub fn try_astype<A>(&self) -> (Series<A>, Vec<usize>)
+    where
+        A: BlackJackData + FromStr + Default,
+    {
+        // Floats happily parse "NaN", giving a natural missing value for those targets
+        let missing = "NaN".parse::<A>().unwrap_or_default();
+
+        let mut failed = vec![];
+        let values = self
+            .values
+            .iter()
+            .enumerate()
+            .map(|(position, v)| match v.to_string().parse::<A>() {
+                Ok(val) => val,
+                Err(_) => {
+                    failed.push(position);
+                    missing.clone()
+                }
+            })
+            .collect();
+
+        let mut series = Series::from_vec(values);
+        series.name = self.name.clone();
+        series.index = self.index.clone();
+        (series, failed)
+    }
+
     /// Get a series of the unique elements held in this series
     ///
     /// ## Example
@@ -423,7 +1068,11 @@ where
         // Cannot use `HashSet` as f32 & f64 don't implement Hash
         let mut unique: Vec<T> = vec![];
         let mut values = self.values.clone();
-        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        if values.len() > UNIQUE_PAR_THRESHOLD {
+            values.par_sort_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        }
 
         for val in values {
             if unique.len() > 0 {
@@ -440,6 +1089,41 @@ where
         Series::from_vec(unique)
     }
 
+    /// Get the number of unique elements held in this series
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series: Series<i32> = Series::from_vec(vec![1, 2, 1, 0, 1, 0, 1, 1]);
+    /// assert_eq!(series.nunique(), 3);
+    /// ```
+    pub fn nunique(&self) -> usize
+    where
+        T: PartialOrd + Copy,
+    {
+        self.unique().len()
+    }
+
+    /// Check if the series holds a value equal to `value`
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::arange(0, 5);
+    ///
+    /// assert!(series.contains(&3));
+    /// assert!(!series.contains(&5));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.values.contains(value)
+    }
+
     /// Create a new Series struct from a vector, where T is supported by [`BlackJackData`].
     ///
     /// ## Example
@@ -458,6 +1142,7 @@ where
             name: None,
             dtype,
             values: vec,
+            index: None,
         }
     }
 
@@ -502,6 +1187,36 @@ where
         }
     }
 
+    /// Write the series to `path` as a single column CSV, headed by its name or
+    /// `"<NA>"` if it has none. See [`Writer`] for writing whole dataframes.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::arange(0, 10);
+    /// series.set_name("values");
+    ///
+    /// assert!(series.to_csv("/tmp/test_series_to_csv.csv").is_ok());
+    /// ```
+    pub fn to_csv(&self, path: &str) -> Result<(), BlackJackError>
+    where
+        T: 'static,
+    {
+        if self.is_empty() {
+            return Err(BlackJackError::ValueError(
+                "Cannot write an empty series to CSV.".to_owned(),
+            ));
+        }
+        let mut series = self.clone();
+        if series.name.is_none() {
+            series.set_name("<NA>");
+        }
+        let mut df: DataFrame<i32> = DataFrame::new();
+        df.add_column(series)?;
+        Writer::new(&path).write(df)
+    }
+
     /// Finds the returns a [`Series`] containing the mode(s) of the current
     /// [`Series`]
     pub fn mode(&self) -> Result<Self, BlackJackError>
@@ -519,6 +1234,28 @@ where
         Ok(modes)
     }
 
+    /// Finds the mode(s) of the current [`Series`] like [`Series::mode`], along with
+    /// the number of times each mode occurs.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1, 2, 2, 3, 2]);
+    /// let (modes, count) = series.mode_count().unwrap();
+    ///
+    /// assert_eq!(modes.values, vec![2]);
+    /// assert_eq!(count, 3);
+    /// ```
+    pub fn mode_count(&self) -> Result<(Self, i64), BlackJackError>
+    where
+        T: BlackJackData + PartialOrd + Copy + ToPrimitive,
+    {
+        let modes = self.mode()?;
+        let count = self.values.iter().filter(|v| **v == modes[0]).count() as i64;
+        Ok((modes, count))
+    }
+
     /// Calculate the variance of the series, using either population or sample variance
     /// > Population: `ddof` == 0_f64
     /// > Sample: `ddof` == 1_f64
@@ -560,6 +1297,42 @@ where
             .ok_or_else(|| BlackJackError::from("Failed to calculate stddev of series."))
     }
 
+    /// Calculate the covariance between this series and `other`, using either
+    /// population or sample covariance
+    /// > Population: `ddof` == 0_f64
+    /// > Sample: `ddof` == 1_f64
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let x = Series::from_vec(vec![1, 2, 3, 4]);
+    /// let y = Series::from_vec(vec![2., 4., 6., 8.]);
+    ///
+    /// assert_eq!(x.cov(&y, 1.).unwrap(), 10. / 3.);
+    /// assert_eq!(x.cov(&x, 1.).unwrap(), x.var(1.).unwrap());
+    /// ```
+    pub fn cov<O>(&self, other: &Series<O>, ddof: f64) -> Result<f64, BlackJackError>
+    where
+        T: ToPrimitive,
+        O: BlackJackData + ToPrimitive,
+    {
+        if self.is_empty() {
+            return Err(BlackJackError::ValueError(
+                "Cannot compute covariance of an empty series!".to_owned(),
+            ));
+        }
+        if self.len() != other.len() {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "Source series is of size: {}, and other is of size: {}",
+                self.len(),
+                other.len()
+            )));
+        }
+        funcs::covariance(self.values.as_slice(), other.values.as_slice(), ddof)
+            .ok_or_else(|| BlackJackError::from("Failed to calculate covariance of series."))
+    }
+
     /// Sum a given series, yielding the same type as the elements stored in the
     /// series.
     pub fn sum(&self) -> T
@@ -597,6 +1370,100 @@ where
             .ok_or_else(|| BlackJackError::from("Failed to calculate mean!"))
     }
 
+    /// Sum the series _in parallel_, falling back to [`Series::sum`] for series
+    /// shorter than [`PAR_REDUCE_THRESHOLD`].
+    ///
+    /// Floating point addition isn't associative, so for float series the result
+    /// may differ slightly from [`Series::sum`], and between runs, as values are
+    /// summed in a different order.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::arange(0, 1_000_000_i64);
+    /// assert_eq!(series.par_sum(), series.sum());
+    /// ```
+    pub fn par_sum(&self) -> T
+    where
+        T: Num + Copy + Sum + Send + Sync,
+    {
+        if self.len() < PAR_REDUCE_THRESHOLD {
+            return self.sum();
+        }
+        self.values.par_iter().cloned().sum()
+    }
+
+    /// Average / Mean of the series computed _in parallel_, falling back to
+    /// [`Series::mean`] for series shorter than [`PAR_REDUCE_THRESHOLD`].
+    /// The same caveat as [`Series::par_sum`] applies.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::arange(0, 1_000_001);
+    /// assert_eq!(series.par_mean().unwrap(), 500_000.);
+    /// ```
+    pub fn par_mean(&self) -> Result<f64, BlackJackError>
+    where
+        T: ToPrimitive + Copy + Num + Sum + Send + Sync,
+    {
+        if self.len() < PAR_REDUCE_THRESHOLD {
+            return self.mean();
+        }
+        let total = self
+            .values
+            .par_iter()
+            .map(|v| v.to_f64())
+            .sum::<Option<f64>>()
+            .ok_or_else(|| BlackJackError::from("Failed to calculate mean!"))?;
+        Ok(total / self.len() as f64)
+    }
+
+    /// Weighted average of the series, computed as `sum(x_i * w_i) / sum(w_i)`
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1, 2, 3]);
+    /// let weights = Series::from_vec(vec![3., 1., 0.]);
+    ///
+    /// assert_eq!(series.weighted_mean(&weights).unwrap(), 1.25);
+    /// ```
+    pub fn weighted_mean<W>(&self, weights: &Series<W>) -> Result<f64, BlackJackError>
+    where
+        T: ToPrimitive,
+        W: BlackJackData + ToPrimitive,
+    {
+        if self.len() != weights.len() {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "Series has length: {}, but weights have length: {}",
+                self.len(),
+                weights.len()
+            )));
+        }
+
+        let (total, total_weight) = self.values.iter().zip(weights.values.iter()).fold(
+            (0_f64, 0_f64),
+            |(total, total_weight), (value, weight)| {
+                let weight = weight.to_f64().unwrap();
+                (
+                    total + value.to_f64().unwrap() * weight,
+                    total_weight + weight,
+                )
+            },
+        );
+
+        if total_weight == 0. {
+            return Err(BlackJackError::from(
+                "Total weight is zero, cannot calculate weighted mean.",
+            ));
+        }
+        Ok(total / total_weight)
+    }
+
     /// Calculate the quantile of the series
     ///
     /// ## Example:
@@ -628,6 +1495,47 @@ where
         Ok(qtl)
     }
 
+    /// Winsorize the series, clamping values below the `lower_q` quantile and
+    /// above the `upper_q` quantile to those quantile values.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![-100, 1, 2, 3, 4, 5, 6, 7, 8, 100]);
+    /// let clamped = series.winsorize(0.1, 0.9).unwrap();
+    ///
+    /// assert!(clamped.min().unwrap() > -100.);
+    /// assert!(clamped.max().unwrap() < 100.);
+    /// ```
+    pub fn winsorize(&self, lower_q: f64, upper_q: f64) -> Result<Series<f64>, BlackJackError>
+    where
+        T: ToPrimitive + BlackJackData,
+    {
+        if !(0. <= lower_q && lower_q < upper_q && upper_q <= 1.) {
+            return Err(BlackJackError::ValueError(format!(
+                "Quantiles must satisfy 0 <= lower_q < upper_q <= 1, got {} and {}",
+                lower_q, upper_q
+            )));
+        }
+        if self.is_empty() {
+            return Err(BlackJackError::from("Cannot winsorize an empty series."));
+        }
+
+        let lower = self.quantile(lower_q)?;
+        let upper = self.quantile(upper_q)?;
+
+        let mut series = Series::from_vec(
+            self.values
+                .iter()
+                .map(|v| v.to_f64().unwrap().max(lower).min(upper))
+                .collect(),
+        );
+        series.name = self.name.clone();
+        series.index = self.index.clone();
+        Ok(series)
+    }
+
     /// Calculate the median of a series
     pub fn median(&self) -> Result<f64, BlackJackError>
     where
@@ -638,7 +1546,7 @@ where
                 "Cannot calculate median of an empty series.",
             ));
         }
-        stats::median(self.values.iter().map(|v| v.to_f64().unwrap())).ok_or_else(|| {
+        funcs::median_unsorted(&mut self.values.clone()).ok_or_else(|| {
             BlackJackError::from(
                 r#"Unable to calculate median, please create an issue!
                            as this wasn't expected to ever happen on a non-empty
@@ -647,8 +1555,101 @@ where
         })
     }
 
+    /// Compute a histogram of the series, returning the `bins + 1` bin edges
+    /// and the count of values falling in each bin. The last bin is closed on
+    /// both ends so the maximum value is counted. A constant series results in
+    /// a single bin holding every value.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![0, 1, 1, 2, 3]);
+    /// let (edges, counts) = series.hist(3).unwrap();
+    ///
+    /// assert_eq!(edges, vec![0., 1., 2., 3.]);
+    /// assert_eq!(counts, vec![1, 2, 2]);
+    /// ```
+    pub fn hist(&self, bins: usize) -> Result<(Vec<f64>, Vec<i64>), BlackJackError>
+    where
+        T: ToPrimitive,
+    {
+        if bins == 0 {
+            return Err(BlackJackError::ValueError(
+                "Number of bins must be greater than zero.".to_owned(),
+            ));
+        }
+
+        let values = self
+            .values
+            .iter()
+            .filter_map(|v| v.to_f64())
+            .filter(|v| !v.is_nan())
+            .collect::<Vec<f64>>();
+
+        if values.is_empty() {
+            return Err(BlackJackError::ValueError(
+                "Cannot compute histogram of an empty series.".to_owned(),
+            ));
+        }
+
+        let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+        // All values equal, no range to divide into bins.
+        if min == max {
+            return Ok((vec![min, max], vec![values.len() as i64]));
+        }
+
+        let width = (max - min) / bins as f64;
+        let edges = (0..=bins)
+            .map(|i| {
+                if i == bins {
+                    max
+                } else {
+                    min + width * i as f64
+                }
+            })
+            .collect::<Vec<f64>>();
+
+        let mut counts = vec![0_i64; bins];
+        for value in values {
+            let bin = (((value - min) / width) as usize).min(bins - 1);
+            counts[bin] += 1;
+        }
+        Ok((edges, counts))
+    }
+
+    /// Same as [`Series::hist`], but with each bin given as its `(low, high)`
+    /// range. A constant series results in one degenerate bin, while an empty
+    /// series or zero `bins` results in no bins at all.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![0, 1, 1, 2, 3]);
+    /// let (ranges, counts) = series.histogram(3);
+    ///
+    /// assert_eq!(ranges, vec![(0., 1.), (1., 2.), (2., 3.)]);
+    /// assert_eq!(counts, vec![1, 2, 2]);
+    /// ```
+    pub fn histogram(&self, bins: usize) -> (Vec<(f64, f64)>, Vec<i64>)
+    where
+        T: ToPrimitive,
+    {
+        match self.hist(bins) {
+            Ok((edges, counts)) => {
+                let ranges = edges.windows(2).map(|edge| (edge[0], edge[1])).collect();
+                (ranges, counts)
+            }
+            Err(_) => (vec![], vec![]),
+        }
+    }
+
     /// Find the minimum of the series. If several elements are equally minimum,
-    /// the first element is returned. If it's empty, an Error will be returned.
+    /// the first element is returned. NaN values are skipped, if it's empty or
+    /// holds only NaNs, an Error will be returned.
     ///
     /// ## Example
     /// ```
@@ -683,6 +1684,23 @@ where
         self.values.len()
     }
 
+    /// Approximate number of bytes used by the values of this series; the
+    /// `size_of` each element plus, for `String`s, the length of their contents.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series: Series<i64> = Series::arange(0, 10).astype().unwrap();
+    /// assert_eq!(series.memory_usage(), 80);
+    /// ```
+    pub fn memory_usage(&self) -> usize {
+        self.values
+            .iter()
+            .map(|v| std::mem::size_of::<T>() + v.heap_size())
+            .sum()
+    }
+
     /// Determine if series is empty.
     pub fn is_empty(&self) -> bool {
         self.len() == 0
@@ -710,6 +1728,196 @@ where
     pub fn append<V: Into<T>>(&mut self, val: V) -> () {
         let v = val.into();
         self.values.push(v);
+        self.extend_index();
+    }
+
+    /// Extend the Series with the elements of an iterator, setting the dtype
+    /// if the series was previously empty.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::from_vec(vec![0, 1, 2]);
+    /// series.extend(3..6);
+    ///
+    /// assert_eq!(series.values, vec![0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.values.extend(iter);
+        self.extend_index();
+        if self.dtype.is_none() && !self.values.is_empty() {
+            self.dtype = Some(self.values[0].dtype());
+        }
+    }
+
+    /// Append a slice of values to the Series, setting the dtype if the
+    /// series was previously empty.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::from_vec(vec![0, 1, 2]);
+    /// series.append_slice(&[3, 4]);
+    ///
+    /// assert_eq!(series.values, vec![0, 1, 2, 3, 4]);
+    /// ```
+    pub fn append_slice(&mut self, vals: &[T]) {
+        self.extend(vals.iter().cloned());
+    }
+
+    /// Append a vector of values to the Series, taking ownership of them;
+    /// otherwise behaves like [`Series::append_slice`].
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::from_vec(vec![0, 1, 2]);
+    /// series.append_vec(vec![3, 4]);
+    ///
+    /// assert_eq!(series.values, vec![0, 1, 2, 3, 4]);
+    /// ```
+    pub fn append_vec(&mut self, vals: Vec<T>) {
+        self.extend(vals);
+    }
+
+    /// Insert a value at `idx`, shifting all values after it to the right.
+    /// Returns an error if `idx` is greater than the length of the series.
+    ///
+    /// If the series has an explicit index, the new value is labeled one
+    /// past the current largest label.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::from_vec(vec![0, 2]);
+    /// series.insert(1, 1).unwrap();
+    ///
+    /// assert_eq!(series.values, vec![0, 1, 2]);
+    /// assert!(series.insert(4, 3).is_err());
+    /// ```
+    pub fn insert(&mut self, idx: usize, value: T) -> Result<(), BlackJackError> {
+        if idx > self.len() {
+            return Err(BlackJackError::ValueError(format!(
+                "Position {} is out of bounds for insertion into length {}",
+                idx,
+                self.len()
+            )));
+        }
+        if self.dtype.is_none() {
+            self.dtype = Some(value.dtype());
+        }
+        self.values.insert(idx, value);
+        if let Some(index) = self.index.as_mut() {
+            let label = index.iter().max().map_or(0, |max| max + 1);
+            index.insert(idx, label);
+        }
+        Ok(())
+    }
+
+    /// Remove and return the value at `idx`, shifting all values after it to the left.
+    /// Returns an error if `idx` is out of bounds.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::from_vec(vec![0, 1, 2]);
+    ///
+    /// assert_eq!(series.remove(1).unwrap(), 1);
+    /// assert_eq!(series.values, vec![0, 2]);
+    /// assert!(series.remove(2).is_err());
+    /// ```
+    pub fn remove(&mut self, idx: usize) -> Result<T, BlackJackError> {
+        check_positions(&[idx], self.len())?;
+        if let Some(index) = self.index.as_mut() {
+            index.remove(idx);
+        }
+        Ok(self.values.remove(idx))
+    }
+
+    /// Shorten the series, keeping the first `len` values; has no effect if
+    /// `len` is greater than the current length.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::arange(0, 5);
+    /// series.truncate(2);
+    ///
+    /// assert_eq!(series.values, vec![0, 1]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        self.values.truncate(len);
+        if let Some(index) = self.index.as_mut() {
+            index.truncate(len);
+        }
+    }
+
+    /// Resize the series to `new_len`, truncating when shrinking and appending
+    /// `fill` when growing, as `Vec::resize` does.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::arange(0, 3);
+    /// series.resize(5, -1);
+    ///
+    /// assert_eq!(series.values, vec![0, 1, 2, -1, -1]);
+    /// ```
+    pub fn resize(&mut self, new_len: usize, fill: T) {
+        if new_len <= self.len() {
+            self.truncate(new_len);
+        } else {
+            let extra = new_len - self.len();
+            self.extend(std::iter::repeat_n(fill, extra));
+        }
+    }
+
+    /// Remove all values from the series, resetting its dtype to `None`.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::arange(0, 5);
+    /// series.clear();
+    ///
+    /// assert!(series.is_empty());
+    /// assert_eq!(series.dtype(), None);
+    /// ```
+    pub fn clear(&mut self) {
+        self.truncate(0);
+        self.dtype = None;
+    }
+
+    /// Create an empty series with the same name and dtype as this one, for
+    /// accumulating values into.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut series = Series::arange(0, 5);
+    /// series.set_name("values");
+    ///
+    /// let empty = series.clone_empty();
+    /// assert!(empty.is_empty());
+    /// assert_eq!(empty.name(), Some("values".to_string()));
+    /// assert_eq!(empty.dtype(), Some(DType::I32));
+    /// ```
+    pub fn clone_empty(&self) -> Series<T> {
+        Series {
+            name: self.name.clone(),
+            values: vec![],
+            dtype: self.dtype.clone(),
+            index: self.index.as_ref().map(|_| vec![]),
+        }
     }
 
     /// As boxed pointer, recoverable by `Box::from_raw(ptr)` or
@@ -723,6 +1931,46 @@ where
         unsafe { *Box::from_raw(ptr) }
     }
 
+    /// Map each value to a `u64` key which is equal for equal values, allowing
+    /// values, including floats which aren't `Hash`, to key a `HashMap`.
+    ///
+    /// Integers are keyed by their value and floats by their bit pattern, with
+    /// `-0.0` keyed as `0.0` and every `NaN` sharing a single key.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1.5, 2.5, 1.5]);
+    /// let keys = series.to_hashable_keys();
+    ///
+    /// assert_eq!(keys[0], keys[2]);
+    /// assert_ne!(keys[0], keys[1]);
+    /// ```
+    pub fn to_hashable_keys(&self) -> Vec<u64>
+    where
+        T: ToPrimitive,
+    {
+        // Dispatched on the values, as `dtype` isn't set on every series
+        match self.values.first().map(|v| v.dtype()) {
+            Some(DType::I64) | Some(DType::I32) => self
+                .values
+                .iter()
+                .map(|v| v.to_i64().unwrap() as u64)
+                .collect(),
+            _ => self
+                .values
+                .iter()
+                .map(|v| match v.to_f64() {
+                    Some(v) if v.is_nan() => f64::NAN.to_bits(),
+                    Some(0.) => 0_f64.to_bits(),
+                    Some(v) => v.to_bits(),
+                    None => f64::NAN.to_bits(),
+                })
+                .collect(),
+        }
+    }
+
     /// Group by method for grouping elements in a [`Series`]
     /// by key.
     ///
@@ -745,8 +1993,7 @@ where
     where
         T: ToPrimitive,
     {
-        /* TODO: Revisit this to avoid the clones. Needs to keep the groups
-           in order based on key order; match pandas. ie:
+        /* Groups are kept in order based on key order; match pandas. ie:
 
             >>> pd.Series([1, 2, 3, 1, 2, 3]).groupby([4, 5, 6, 4, 5, 6]).sum()
             4    2
@@ -756,28 +2003,177 @@ where
         */
         use indexmap::IndexMap;
 
-        let values = self.values.clone();
-
-        let mut map: IndexMap<String, Vec<T>> = IndexMap::new();
-
-        // Group values by their keys
-        for (k, v) in keys.values.iter().zip(values.iter()) {
-            let key = k.to_string();
-            let mr = map.entry(key).or_insert(vec![]);
-            mr.push(v.clone());
-        }
+        // Group positions by their keys, values are only cloned once into their group.
+        // Integer keys are grouped by value, only formatting each group's name once;
+        // dispatched on the keys' values as `dtype` isn't set on every series.
+        let grouped: Vec<(String, Vec<usize>)> = match keys.values.first().map(|k| k.dtype()) {
+            Some(DType::I64) | Some(DType::I32) => {
+                let mut map: IndexMap<i64, Vec<usize>> = IndexMap::new();
+                for (position, k) in keys.values.iter().take(self.len()).enumerate() {
+                    map.entry(k.to_i64().unwrap()).or_default().push(position);
+                }
+                map.into_iter()
+                    .map(|(k, positions)| (k.to_string(), positions))
+                    .collect()
+            }
+            _ => {
+                let mut map: IndexMap<String, Vec<usize>> = IndexMap::new();
+                for (position, k) in keys.values.iter().take(self.len()).enumerate() {
+                    map.entry(k.to_string()).or_default().push(position);
+                }
+                map.into_iter().collect()
+            }
+        };
 
         // Create new series from the previous mapping.
-        let groups = map
+        let groups = grouped
             .iter()
-            .map(|(name, values)| {
-                let mut series = Series::from_vec(values.clone());
+            .map(|(name, positions)| {
+                let mut series = Series::from_vec(
+                    positions
+                        .iter()
+                        .map(|position| self.values[*position].clone())
+                        .collect(),
+                );
                 series.set_name(name.as_str());
                 series
             })
             .collect();
 
-        SeriesGroupBy::new(groups)
+        SeriesGroupBy::with_positions(
+            groups,
+            grouped.into_iter().map(|(_, p)| p).collect(),
+            self.name.clone(),
+        )
+    }
+
+    /// Elementwise `>` comparison against a scalar, producing a boolean mask
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::arange(0, 4);
+    /// assert_eq!(series.gt(1).values, vec![false, false, true, true]);
+    /// ```
+    pub fn gt(&self, scalar: T) -> Series<bool>
+    where
+        T: PartialOrd + Copy,
+    {
+        self.compare(|v| *v > scalar)
+    }
+
+    /// Elementwise `<` comparison against a scalar, producing a boolean mask
+    pub fn lt(&self, scalar: T) -> Series<bool>
+    where
+        T: PartialOrd + Copy,
+    {
+        self.compare(|v| *v < scalar)
+    }
+
+    /// Elementwise `>=` comparison against a scalar, producing a boolean mask
+    pub fn ge(&self, scalar: T) -> Series<bool>
+    where
+        T: PartialOrd + Copy,
+    {
+        self.compare(|v| *v >= scalar)
+    }
+
+    /// Elementwise `<=` comparison against a scalar, producing a boolean mask
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1.5, 2.5, 3.5]);
+    /// assert_eq!(series.le(2.5).values, vec![true, true, false]);
+    /// ```
+    pub fn le(&self, scalar: T) -> Series<bool>
+    where
+        T: PartialOrd + Copy,
+    {
+        self.compare(|v| *v <= scalar)
+    }
+
+    /// Elementwise `==` comparison against a scalar, producing a boolean mask
+    pub fn eq_scalar(&self, scalar: T) -> Series<bool>
+    where
+        T: PartialOrd + Copy,
+    {
+        self.compare(|v| *v == scalar)
+    }
+
+    /// Elementwise `!=` comparison against a scalar, producing a boolean mask
+    pub fn ne_scalar(&self, scalar: T) -> Series<bool>
+    where
+        T: PartialOrd + Copy,
+    {
+        self.compare(|v| *v != scalar)
+    }
+
+    /// Produce a boolean mask marking the elements which are present in `set`,
+    /// pairs with [`Series::filter_mask`] for selecting by membership.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1, 2, 3, 4]);
+    /// assert_eq!(series.is_in(&[2, 4]).values, vec![false, true, false, true]);
+    /// ```
+    pub fn is_in(&self, set: &[T]) -> Series<bool>
+    where
+        T: PartialEq,
+    {
+        self.compare(|v| set.contains(v))
+    }
+
+    /// Select the elements where `mask` is `true`, keeping their index labels.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::arange(0, 10);
+    /// let filtered = series.filter_mask(&series.gt(5)).unwrap();
+    ///
+    /// assert_eq!(filtered.values, vec![6, 7, 8, 9]);
+    /// ```
+    pub fn filter_mask(&self, mask: &Series<bool>) -> Result<Series<T>, BlackJackError> {
+        if mask.len() != self.len() {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "Series has length: {}, but mask has length: {}",
+                self.len(),
+                mask.len()
+            )));
+        }
+        let keep = |position: &usize| mask[*position];
+
+        let mut series = Series::from_vec(
+            (0..self.len())
+                .filter(keep)
+                .map(|position| self.values[position].clone())
+                .collect(),
+        );
+        series.name = self.name.clone();
+        series.index = self.index.as_ref().map(|index| {
+            (0..index.len())
+                .filter(keep)
+                .map(|position| index[position])
+                .collect()
+        });
+        Ok(series)
+    }
+
+    /// Build a boolean mask from a condition, keeping the name and index
+    fn compare<F>(&self, condition: F) -> Series<bool>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut mask = Series::from_vec(self.values.iter().map(condition).collect());
+        mask.name = self.name.clone();
+        mask.index = self.index.clone();
+        mask
     }
 
     /// Find the _positions_ where a condition is true
@@ -814,6 +2210,225 @@ where
     }
 }
 
+/// Support Series creation from a `Vec`, equivalent to [`Series::from_vec`]
+///
+/// ## Example
+/// ```
+/// use blackjack::prelude::*;
+///
+/// let series: Series<i32> = vec![1, 2, 3].into();
+///
+/// assert_eq!(series.values, vec![1, 2, 3]);
+/// assert_eq!(series.dtype(), Some(DType::I32));
+/// ```
+impl<T> From<Vec<T>> for Series<T>
+where
+    T: BlackJackData,
+{
+    fn from(vec: Vec<T>) -> Series<T> {
+        Series::from_vec(vec)
+    }
+}
+
+/// Support Series creation from a slice, cloning its elements
+///
+/// ## Example
+/// ```
+/// use blackjack::prelude::*;
+///
+/// let values = [1.5_f64, 2.5, 3.5];
+/// let series: Series<f64> = Series::from(&values[1..]);
+///
+/// assert_eq!(series.values, vec![2.5, 3.5]);
+/// ```
+impl<T> From<&[T]> for Series<T>
+where
+    T: BlackJackData + Clone,
+{
+    fn from(slice: &[T]) -> Series<T> {
+        Series::from_vec(slice.to_vec())
+    }
+}
+
+/// Support collecting an iterator directly into a `Series`
+///
+/// ## Example
+/// ```
+/// use blackjack::prelude::*;
+///
+/// let series: Series<i32> = (0..5).map(|x| x * x).collect();
+///
+/// assert_eq!(series.values, vec![0, 1, 4, 9, 16]);
+/// assert_eq!(series.dtype(), Some(DType::I32));
+/// ```
+impl<T> FromIterator<T> for Series<T>
+where
+    T: BlackJackData,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Series::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<T> Extend<T> for Series<T>
+where
+    T: BlackJackData,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        Series::extend(self, iter)
+    }
+}
+
+/// Specialized methods for `f64` series
+impl Series<f64> {
+    /// Sum the series, processing values in chunks which vectorizes
+    /// better than the generic [`Series::sum`].
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1., 2., 3.5]);
+    /// assert_eq!(series.sum_f64(), 6.5);
+    /// ```
+    pub fn sum_f64(&self) -> f64 {
+        funcs::sum_f64(self.values.as_slice())
+    }
+
+    /// Whether both series have the same length and each pair of values differs
+    /// by no more than `epsilon`. Values which are both `NaN` are considered equal.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![0.1 + 0.2, 1.]);
+    /// let other = Series::from_vec(vec![0.3, 1.]);
+    ///
+    /// assert!(series != other);
+    /// assert!(series.approx_eq(&other, 1e-12));
+    /// ```
+    pub fn approx_eq(&self, other: &Series<f64>, epsilon: f64) -> bool {
+        self.len() == other.len()
+            && self
+                .values
+                .iter()
+                .zip(other.values.iter())
+                .all(|(a, b)| (a.is_nan() && b.is_nan()) || (a - b).abs() <= epsilon)
+    }
+
+    /// Round each value to the nearest integer, with halfway cases rounded
+    /// away from zero (ie. `2.5` becomes `3` and `-2.5` becomes `-3`).
+    ///
+    /// The cast saturates; `NaN` becomes `0` and values beyond the range of
+    /// `i64` are clamped to `i64::MIN` or `i64::MAX`.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1.4, 1.6, 2.5]);
+    /// assert_eq!(series.round_to_i64().values, vec![1, 2, 3]);
+    /// ```
+    pub fn round_to_i64(&self) -> Series<i64> {
+        self.map_to_i64(f64::round)
+    }
+
+    /// Round each value down to an integer, see [`Series::round_to_i64`]
+    pub fn floor_to_i64(&self) -> Series<i64> {
+        self.map_to_i64(f64::floor)
+    }
+
+    /// Round each value up to an integer, see [`Series::round_to_i64`]
+    pub fn ceil_to_i64(&self) -> Series<i64> {
+        self.map_to_i64(f64::ceil)
+    }
+
+    fn map_to_i64(&self, func: fn(f64) -> f64) -> Series<i64> {
+        let mut series = Series::from_vec(self.values.iter().map(|v| func(*v) as i64).collect());
+        series.name = self.name.clone();
+        series.index = self.index.clone();
+        series
+    }
+}
+
+/// Specialized methods for `f32` series
+impl Series<f32> {
+    /// Sum the series, processing values in chunks which vectorizes
+    /// better than the generic [`Series::sum`].
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1_f32, 2., 3.5]);
+    /// assert_eq!(series.sum_f32(), 6.5);
+    /// ```
+    pub fn sum_f32(&self) -> f32 {
+        funcs::sum_f32(self.values.as_slice())
+    }
+}
+
+/// Specialized methods for `String` series
+impl Series<String> {
+    /// Encode the series as integer codes, returned alongside the categories
+    /// they index into, in order of first appearance. The codes keep the
+    /// name and index of the series.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec!["b".to_string(), "a".to_string(), "b".to_string()]);
+    /// let (codes, categories) = series.factorize();
+    ///
+    /// assert_eq!(codes.values, vec![0, 1, 0]);
+    /// assert_eq!(categories, vec!["b".to_string(), "a".to_string()]);
+    /// ```
+    pub fn factorize(&self) -> (Series<i64>, Vec<String>) {
+        let mut categories: Vec<String> = vec![];
+        let mut lookup: HashMap<&str, i64> = HashMap::new();
+        let codes = self
+            .values
+            .iter()
+            .map(|value| {
+                *lookup.entry(value.as_str()).or_insert_with(|| {
+                    categories.push(value.clone());
+                    categories.len() as i64 - 1
+                })
+            })
+            .collect();
+
+        let mut series = Series::from_vec(codes);
+        series.name = self.name.clone();
+        series.index = self.index.clone();
+        (series, categories)
+    }
+
+    /// Read only the column `column` from the CSV file at `path`, skipping the
+    /// others, with its dtype inferred as [`Reader::read`] would. See [`Reader`]
+    /// for reading whole files and non default CSV params.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let path = format!("{}/tests/data/basic_csv.csv", env!("CARGO_MANIFEST_DIR"));
+    ///
+    /// if let GenericSeriesContainer::I32(series) = Series::read_csv_column(&path, "col2").unwrap() {
+    ///     assert_eq!(series.values, vec![1, 2, 3, 4, 5]);
+    /// } else {
+    ///     panic!("Expected col2 to be read as i32");
+    /// }
+    /// ```
+    pub fn read_csv_column(
+        path: &str,
+        column: &str,
+    ) -> Result<GenericSeriesContainer, BlackJackError> {
+        Reader::new(&path).read_column(column)
+    }
+}
+
 // Support ref indexing
 impl<T> Index<usize> for Series<T>
 where
@@ -157,3 +157,225 @@ where
             .collect::<Vec<()>>();
     }
 }
+
+/// Checked arithmetic for integer series
+impl<T> Series<T>
+where
+    T: BlackJackData + CheckedDiv + Send + Sync,
+{
+    /// Divide the series by a scalar, returning an error rather than panicking
+    /// when dividing by zero or on overflow. Only available for integer types,
+    /// use `series / scalar` for floats.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![2, 4, 6]);
+    ///
+    /// assert_eq!(series.clone().checked_div(2).unwrap().values, vec![1, 2, 3]);
+    /// assert!(series.checked_div(0).is_err());
+    /// ```
+    pub fn checked_div(self, scalar_val: T) -> Result<Series<T>, BlackJackError> {
+        let vec = self
+            .values
+            .par_iter()
+            .map(|v| v.checked_div(&scalar_val))
+            .collect::<Option<Vec<T>>>()
+            .ok_or_else(|| {
+                BlackJackError::ValueError(format!(
+                    "Unable to divide series by {}, division by zero or overflow",
+                    scalar_val
+                ))
+            })?;
+        Ok(Series::from_vec(vec))
+    }
+}
+
+/// Index aligned arithmetic, operating on values sharing the same index label
+/// rather than the same position. See [`Series::set_index`]
+impl<T> Series<T>
+where
+    T: BlackJackData + ToPrimitive,
+{
+    /// Add two series by index label, the result holds the sorted union of both
+    /// indexes where labels missing from either series result in NaN.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let mut s1 = Series::from_vec(vec![1, 2, 3]);
+    /// s1.set_index(vec![0, 1, 2]).unwrap();
+    ///
+    /// let mut s2 = Series::from_vec(vec![10, 20]);
+    /// s2.set_index(vec![2, 1]).unwrap();
+    ///
+    /// let result = s1.add_aligned(&s2);
+    /// assert!(result[0].is_nan());
+    /// assert_eq!(result[1], 22.);
+    /// assert_eq!(result[2], 13.);
+    /// ```
+    pub fn add_aligned<O>(&self, other: &Series<O>) -> Series<f64>
+    where
+        O: BlackJackData + ToPrimitive,
+    {
+        self.aligned_op(other, |a, b| a + b)
+    }
+
+    /// Subtract two series by index label, see [`Series::add_aligned`]
+    pub fn sub_aligned<O>(&self, other: &Series<O>) -> Series<f64>
+    where
+        O: BlackJackData + ToPrimitive,
+    {
+        self.aligned_op(other, |a, b| a - b)
+    }
+
+    /// Multiply two series by index label, see [`Series::add_aligned`]
+    pub fn mul_aligned<O>(&self, other: &Series<O>) -> Series<f64>
+    where
+        O: BlackJackData + ToPrimitive,
+    {
+        self.aligned_op(other, |a, b| a * b)
+    }
+
+    /// Divide two series by index label, see [`Series::add_aligned`]
+    pub fn div_aligned<O>(&self, other: &Series<O>) -> Series<f64>
+    where
+        O: BlackJackData + ToPrimitive,
+    {
+        self.aligned_op(other, |a, b| a / b)
+    }
+
+    fn aligned_op<O, F>(&self, other: &Series<O>, op: F) -> Series<f64>
+    where
+        O: BlackJackData + ToPrimitive,
+        F: Fn(f64, f64) -> f64,
+    {
+        let left = self.label_positions();
+        let right = other.label_positions();
+
+        let mut labels = left
+            .keys()
+            .chain(right.keys())
+            .cloned()
+            .collect::<Vec<i64>>();
+        labels.sort();
+        labels.dedup();
+
+        let values = labels
+            .iter()
+            .map(|label| match (left.get(label), right.get(label)) {
+                (Some(l), Some(r)) => op(
+                    self.values[*l].to_f64().unwrap_or(f64::NAN),
+                    other.values[*r].to_f64().unwrap_or(f64::NAN),
+                ),
+                _ => f64::NAN,
+            })
+            .collect::<Vec<f64>>();
+
+        let mut series = Series::from_vec(values);
+        series.set_index(labels).unwrap();
+        series
+    }
+}
+
+/// Positional arithmetic between series of possibly different numeric types,
+/// casting both sides to `f64`.
+impl<T> Series<T>
+where
+    T: BlackJackData + ToPrimitive,
+{
+    /// Subtract `other` from this series elementwise by position, regardless of
+    /// either series' numeric type.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let actual = Series::from_vec(vec![1.5_f64, 2.5, 4.0]);
+    /// let predicted = Series::from_vec(vec![1_i32, 3, 4]);
+    ///
+    /// let residuals = actual.subtract_series(&predicted).unwrap();
+    /// assert_eq!(residuals.values, vec![0.5, -0.5, 0.0]);
+    /// ```
+    pub fn subtract_series<O>(&self, other: &Series<O>) -> Result<Series<f64>, BlackJackError>
+    where
+        O: BlackJackData + ToPrimitive,
+    {
+        self.sub_f64(other)
+    }
+
+    /// Add two series elementwise by position, casting both to `f64` so that
+    /// series of different numeric types can be combined.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let s1 = Series::from_vec(vec![1_i32, 2, 3]);
+    /// let s2 = Series::from_vec(vec![0.5_f64, 0.5, 0.5]);
+    ///
+    /// assert_eq!(s1.add_f64(&s2).unwrap().values, vec![1.5, 2.5, 3.5]);
+    /// ```
+    pub fn add_f64<O>(&self, other: &Series<O>) -> Result<Series<f64>, BlackJackError>
+    where
+        O: BlackJackData + ToPrimitive,
+    {
+        self.positional_op(other, |a, b| a + b)
+    }
+
+    /// Subtract two series by position as `f64`, see [`Series::add_f64`]
+    pub fn sub_f64<O>(&self, other: &Series<O>) -> Result<Series<f64>, BlackJackError>
+    where
+        O: BlackJackData + ToPrimitive,
+    {
+        self.positional_op(other, |a, b| a - b)
+    }
+
+    /// Multiply two series by position as `f64`, see [`Series::add_f64`]
+    pub fn mul_f64<O>(&self, other: &Series<O>) -> Result<Series<f64>, BlackJackError>
+    where
+        O: BlackJackData + ToPrimitive,
+    {
+        self.positional_op(other, |a, b| a * b)
+    }
+
+    /// Divide two series by position as `f64`, see [`Series::add_f64`]
+    pub fn div_f64<O>(&self, other: &Series<O>) -> Result<Series<f64>, BlackJackError>
+    where
+        O: BlackJackData + ToPrimitive,
+    {
+        self.positional_op(other, |a, b| a / b)
+    }
+
+    fn positional_op<O, F>(&self, other: &Series<O>, op: F) -> Result<Series<f64>, BlackJackError>
+    where
+        O: BlackJackData + ToPrimitive,
+        F: Fn(f64, f64) -> f64,
+    {
+        if self.len() != other.len() {
+            return Err(BlackJackError::LengthMismatch(format!(
+                "Source series is of size: {}, and other is of size: {}",
+                self.len(),
+                other.len()
+            )));
+        }
+        let values = self
+            .values
+            .iter()
+            .zip(&other.values)
+            .map(|(a, b)| {
+                op(
+                    a.to_f64().unwrap_or(f64::NAN),
+                    b.to_f64().unwrap_or(f64::NAN),
+                )
+            })
+            .collect::<Vec<f64>>();
+
+        let mut series = Series::from_vec(values);
+        series.name = self.name.clone();
+        series.index = self.index.clone();
+        Ok(series)
+    }
+}
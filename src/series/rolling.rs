@@ -1,9 +1,8 @@
 //! `.rolling()` functionality for `Series`
 
-use std::iter::Sum;
 use std::marker::{Send, Sync};
+use std::ops::Range;
 
-use ndarray::aview1 as arrayview;
 use num::*;
 use rayon::prelude::*;
 
@@ -37,11 +36,59 @@ where
     T: BlackJackData + Send + Sync,
 {
     window: usize,
+    min_periods: usize,
+    ignore_nan: bool,
+    center: bool,
     series: &'a Series<T>,
-    nans: Vec<f64>,
 }
 
-// TODO: These impls need to be refactored (DRY) - lots of repeated code
+/// Aggregations which can be computed together with [`Rolling::aggregate`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RollingAgg {
+    /// Sum of each window, see [`Rolling::sum`]
+    Sum,
+    /// Mean of each window, see [`Rolling::mean`]
+    Mean,
+    /// Variance of each window with the given `ddof`, see [`Rolling::var`]
+    Var(f64),
+    /// Standard deviation of each window with the given `ddof`, see [`Rolling::std`]
+    Std(f64),
+    /// Median of each window, see [`Rolling::median`]
+    Median,
+    /// Min of each window, see [`Rolling::min`]
+    Min,
+    /// Max of each window, see [`Rolling::max`]
+    Max,
+}
+
+impl RollingAgg {
+    /// Name of the column holding this aggregation in [`Rolling::aggregate`]
+    pub fn name(&self) -> &'static str {
+        match self {
+            RollingAgg::Sum => "sum",
+            RollingAgg::Mean => "mean",
+            RollingAgg::Var(_) => "var",
+            RollingAgg::Std(_) => "std",
+            RollingAgg::Median => "median",
+            RollingAgg::Min => "min",
+            RollingAgg::Max => "max",
+        }
+    }
+
+    /// Compute this aggregation over a single window
+    fn compute(&self, window: &[f64]) -> f64 {
+        match self {
+            RollingAgg::Sum => window.iter().sum(),
+            RollingAgg::Mean => window.iter().sum::<f64>() / window.len() as f64,
+            RollingAgg::Var(ddof) => funcs::variance(window, *ddof).unwrap_or(f64::NAN),
+            RollingAgg::Std(ddof) => funcs::std(window, *ddof).unwrap_or(f64::NAN),
+            RollingAgg::Median => funcs::median_unsorted(&mut window.to_vec()).unwrap_or(f64::NAN),
+            RollingAgg::Min => funcs::min(window).copied().unwrap_or(f64::NAN),
+            RollingAgg::Max => funcs::max(window).copied().unwrap_or(f64::NAN),
+        }
+    }
+}
+
 impl<'a, T> Rolling<'a, T>
 where
     T: BlackJackData + Send + Sync,
@@ -57,64 +104,289 @@ where
     /// let roller = Series::from_vec(vec![0, 1, 2, 3]).rolling(2);
     /// ```
     pub fn new(window: usize, series: &'a Series<T>) -> Self {
-        let nans: Vec<f64> = (0..window - 1).into_iter().map(|_| Float::nan()).collect();
         Rolling {
             window,
+            min_periods: window,
+            ignore_nan: false,
+            center: false,
             series,
-            nans,
         }
     }
 
+    /// Set the minimum number of observations required in a window to produce
+    /// a value, defaults to the size of the window. Positions with fewer
+    /// observations are NaN.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1., 2., 3., 4.]);
+    ///
+    /// let rolled = series.rolling(3).min_periods(1).sum().unwrap();
+    /// assert_eq!(rolled.values, vec![1., 3., 6., 9.]);
+    /// ```
+    pub fn min_periods(self, n: usize) -> Self {
+        let mut rolling = self;
+        rolling.min_periods = n;
+        rolling
+    }
+
+    /// Skip NaN values within each window rather than letting them make the
+    /// whole window NaN, defaults to `false`. Reductions are then computed over
+    /// the remaining values, and `min_periods` counts only those values.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1., f64::NAN, 3., 5.]);
+    ///
+    /// let rolled = series.rolling(2).ignore_nan(true).min_periods(1).mean().unwrap();
+    /// assert_eq!(rolled.values, vec![1., 1., 3., 4.]);
+    /// ```
+    pub fn ignore_nan(self, yes: bool) -> Self {
+        let mut rolling = self;
+        rolling.ignore_nan = yes;
+        rolling
+    }
+
+    /// Assign the result of each window to its center position rather than its
+    /// right edge, defaults to `false`. Both ends are then padded with NaNs, or
+    /// computed over partial windows when `min_periods` allows. For even windows
+    /// the center is taken to the right of the middle.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1., 2., 3., 4., 5.]);
+    ///
+    /// let rolled = series.rolling(3).center(true).sum().unwrap();
+    /// assert!(rolled[0].is_nan());
+    /// assert_eq!(rolled[1..4], [6., 9., 12.]);
+    /// assert!(rolled[4].is_nan());
+    /// ```
+    pub fn center(self, yes: bool) -> Self {
+        let mut rolling = self;
+        rolling.center = yes;
+        rolling
+    }
+
+    /// Number of positions the result of a window is moved back from its right edge
+    fn offset(&self) -> usize {
+        if self.center {
+            self.window.saturating_sub(1) / 2
+        } else {
+            0
+        }
+    }
+
+    /// The range of values for each window, `None` where the window holds
+    /// fewer than `min_periods` observations.
+    fn window_ranges(&self) -> Vec<Option<Range<usize>>> {
+        let len = self.series.len();
+        let offset = self.offset();
+        (0..len)
+            .map(|idx| {
+                let end = (idx + 1 + offset).min(len);
+                let start = (idx + 1 + offset).saturating_sub(self.window);
+                if end - start >= self.min_periods.max(1) {
+                    Some(start..end)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Values of a window as `f64`s, without NaNs if `ignore_nan` is set; `None`
+    /// if that leaves fewer than `min_periods` values.
+    fn window_values(&self, range: Range<usize>) -> Option<Vec<f64>>
+    where
+        T: ToPrimitive,
+    {
+        let values = self.series.values[range]
+            .iter()
+            .map(|v| v.to_f64().unwrap_or(f64::NAN))
+            .filter(|v| !(self.ignore_nan && v.is_nan()))
+            .collect::<Vec<f64>>();
+        if values.len() >= self.min_periods.max(1) {
+            Some(values)
+        } else {
+            None
+        }
+    }
+
+    /// Calculate several aggregations in a single pass over the windows, giving
+    /// a dataframe with a column per aggregation named by [`RollingAgg::name`].
+    ///
+    /// Each window is reduced independently, so sums and means may differ very
+    /// slightly from the running sums of [`Rolling::sum`] and [`Rolling::mean`].
+    /// Returns an error if two aggregations share a name, ie. variances with
+    /// different `ddof`s.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1., 2., 3., 1., 2., 6.]);
+    /// let df = series
+    ///     .rolling(4)
+    ///     .aggregate(&[RollingAgg::Mean, RollingAgg::Max])
+    ///     .unwrap();
+    ///
+    /// let mean: &Series<f64> = df.get_column("mean").unwrap();
+    /// let max: &Series<f64> = df.get_column("max").unwrap();
+    /// assert_eq!(mean[5], 3.0);
+    /// assert_eq!(max[5], 6.0);
+    /// ```
+    pub fn aggregate(&self, aggs: &[RollingAgg]) -> Result<DataFrame<i32>, BlackJackError>
+    where
+        T: ToPrimitive,
+    {
+        for (idx, agg) in aggs.iter().enumerate() {
+            if aggs[..idx].iter().any(|other| other.name() == agg.name()) {
+                return Err(BlackJackError::ValueError(format!(
+                    "Aggregation '{}' given more than once",
+                    agg.name()
+                )));
+            }
+        }
+
+        let rows = self
+            .window_ranges()
+            .into_par_iter()
+            .map(
+                |range| match range.and_then(|range| self.window_values(range)) {
+                    Some(window) => aggs
+                        .iter()
+                        .map(|agg| agg.compute(&window))
+                        .collect::<Vec<f64>>(),
+                    None => vec![f64::NAN; aggs.len()],
+                },
+            )
+            .collect::<Vec<Vec<f64>>>();
+
+        let mut df = DataFrame::new();
+        for (idx, agg) in aggs.iter().enumerate() {
+            let mut series = Series::from_vec(rows.iter().map(|row| row[idx]).collect());
+            series.set_name(agg.name());
+            // Set even when there are no windows, leaving no values to take it from
+            series.dtype = Some(f64::dtype_of());
+            df.add_column(series)?;
+        }
+        Ok(df)
+    }
+
     /// Calculate a rolling mean from the current instance.
+    ///
+    /// Computed in a single pass, see [`Rolling::sum`].
     pub fn mean(&self) -> Result<Series<f64>, BlackJackError>
     where
-        T: Sum + Num + ToPrimitive + Copy,
+        T: Num + ToPrimitive + Copy,
     {
-        // Pre-populate the beginning with NaNs up to window index
-        let mut vals = self.nans.clone();
-
-        // Calculate the remaining valid windows
-        // REMINDER: Using ArrayVeiw and re-implementing .mean() until Series has an ArrayView impl
-        vals.extend(
-            (0..self.series.len() + 1 - self.window)
-                .into_iter()
-                .map(|idx| {
-                    let view = arrayview(&self.series.values[idx..idx + self.window]);
-                    match view.sum().to_f64() {
-                        Some(d) => Ok(d / view.len() as f64),
-                        None => Err(BlackJackError::from("Unable to cast windowed sum to f64.")),
-                    }
-                })
-                .collect::<Result<Vec<f64>, _>>()?,
-        );
-        Ok(Series::from_vec(vals))
+        self.sliding_sum(|sum, n_obs| sum / n_obs as f64)
     }
 
     /// Calculate a rolling sum from the current instance.
+    ///
+    /// Computed in a single pass by maintaining a running sum, adding the element
+    /// entering the window and subtracting the one leaving it. As a result, floating
+    /// point rounding errors accumulate over very long series, and may differ slightly
+    /// from summing each window independently.
     pub fn sum(&self) -> Result<Series<f64>, BlackJackError>
     where
-        T: Sum + Num + ToPrimitive + Copy,
+        T: Num + ToPrimitive + Copy,
+    {
+        self.sliding_sum(|sum, _| sum)
+    }
+
+    /// Single pass over the series keeping a running sum of the window, `finish`
+    /// computes the output from the sum and number of observations in the window.
+    fn sliding_sum<F>(&self, finish: F) -> Result<Series<f64>, BlackJackError>
+    where
+        T: Num + ToPrimitive + Copy,
+        F: Fn(f64, usize) -> f64,
     {
-        // Pre-populate the beginning with NaNs up to window index
-        let mut vals = self.nans.clone();
-
-        // Calculate the remaining valid windows
-        // REMINDER: Using ArrayVeiw and re-implementing .mean() until Series has an ArrayView impl
-        vals.extend(
-            (0..self.series.len() + 1 - self.window)
-                .into_iter()
-                .map(|idx| {
-                    let view = arrayview(&self.series.values[idx..idx + self.window]);
-                    match view.sum().to_f64() {
-                        Some(s) => Ok(s),
-                        None => Err(BlackJackError::from("Unable to cast windowed sum to f64.")),
-                    }
-                })
-                .collect::<Result<Vec<f64>, _>>()?,
-        );
+        let to_f64 = |v: &T| {
+            v.to_f64()
+                .ok_or_else(|| BlackJackError::from("Unable to cast windowed sum to f64."))
+        };
+
+        // NaNs are counted rather than summed, so they don't poison later windows
+        let mut total = 0_f64;
+        let mut nans = 0_usize;
+        let len = self.series.len();
+        let offset = self.offset();
+        let mut vals = Vec::with_capacity(len);
+
+        // Windows end `offset` positions past the one they're assigned to, so keep
+        // sliding beyond the end of the series when centered
+        for end in 0..len + offset {
+            if end < len {
+                let entering = to_f64(&self.series.values[end])?;
+                if entering.is_nan() {
+                    nans += 1;
+                } else {
+                    total += entering;
+                }
+            }
+
+            if end >= self.window {
+                let leaving = to_f64(&self.series.values[end - self.window])?;
+                if leaving.is_nan() {
+                    nans -= 1;
+                } else {
+                    total -= leaving;
+                }
+            }
+
+            if end < offset {
+                continue;
+            }
+
+            let mut n_obs = (end + 1).min(len) - (end + 1).saturating_sub(self.window);
+            if self.ignore_nan {
+                n_obs -= nans;
+            }
+            if (nans == 0 || self.ignore_nan) && n_obs >= self.min_periods.max(1) {
+                vals.push(finish(total, n_obs));
+            } else {
+                vals.push(f64::NAN);
+            }
+        }
         Ok(Series::from_vec(vals))
     }
 
+    /// Apply a custom reducer to each window, preserving the element type.
+    /// Positions holding fewer than `min_periods` observations are set to `fill`.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![5, 6, 7, 8]);
+    ///
+    /// // First element of each window
+    /// let rolled = series.rolling(2).apply_typed(|window| window[0], 0);
+    /// assert_eq!(rolled.values, vec![0, 5, 6, 7]);
+    /// ```
+    pub fn apply_typed<F>(&self, func: F, fill: T) -> Series<T>
+    where
+        F: Fn(&[T]) -> T,
+    {
+        let vals = self
+            .window_ranges()
+            .into_iter()
+            .map(|range| match range {
+                Some(range) => func(&self.series.values[range]),
+                None => fill.clone(),
+            })
+            .collect::<Vec<T>>();
+        Series::from_vec(vals)
+    }
+
     /// Calculate a rolling variance from the current instance, using either population or sample variance
     /// > Population: `ddof` == 0_f64
     /// > Sample: `ddof` == 1_f64
@@ -122,24 +394,7 @@ where
     where
         T: Num + ToPrimitive,
     {
-        // Pre-populate the beginning with NaNs up to window index
-        let mut vals = self.nans.clone();
-
-        // Calculate the remaining valid windows
-        vals.extend(
-            (0..self.series.len() + 1 - self.window)
-                .into_iter()
-                .map(|idx| {
-                    match funcs::variance(&self.series.values[idx..idx + self.window], ddof) {
-                        Some(var) => Ok(var),
-                        None => Err(BlackJackError::from(
-                            "Failed to calculate variance for window",
-                        )),
-                    }
-                })
-                .collect::<Result<Vec<f64>, _>>()?,
-        );
-        Ok(Series::from_vec(vals))
+        Ok(self.reduce(RollingAgg::Var(ddof)))
     }
 
     /// Calculate the rolling standard deviation for each window,
@@ -150,24 +405,7 @@ where
     where
         T: Num + ToPrimitive + Copy,
     {
-        // Pre-populate the beginning with NaNs up to window index
-        let mut vals = self.nans.clone();
-
-        // Calculate the remaining valid windows
-        vals.extend(
-            (0..self.series.len() + 1 - self.window)
-                .into_iter()
-                .map(
-                    |idx| match funcs::std(&self.series.values[idx..idx + self.window], ddof) {
-                        Some(std) => Ok(std),
-                        None => Err(BlackJackError::from(
-                            "Failed to calculate standard deviation for window",
-                        )),
-                    },
-                )
-                .collect::<Result<Vec<f64>, _>>()?,
-        );
-        Ok(Series::from_vec(vals))
+        Ok(self.reduce(RollingAgg::Std(ddof)))
     }
 
     /// Calculate a rolling median from the current instance.
@@ -175,74 +413,41 @@ where
     where
         T: PartialOrd + Num + ToPrimitive + Copy,
     {
-        // Pre-populate the beginning with NaNs up to window index
-        let mut vals = self.nans.clone();
-
-        // Calculate the remaining valid windows
-        // REMINDER: Using ArrayVeiw and re-implementing .mean() until Series has an ArrayView impl
-        vals.extend(
-            (0..self.series.len() + 1 - self.window)
-                .into_par_iter()
-                .map(|idx| {
-                    match stats::median(
-                        self.series.values[idx..idx + self.window]
-                            .iter()
-                            .map(|v| *v),
-                    ) {
-                        Some(med) => Ok(med),
-                        None => Err(BlackJackError::from("Failed to compute median for window")),
-                    }
-                })
-                .collect::<Result<Vec<f64>, _>>()?,
-        );
-        Ok(Series::from_vec(vals))
+        Ok(self.reduce(RollingAgg::Median))
     }
 
-    /// Calculate a rolling min from the current instance.
+    /// Calculate a rolling min from the current instance, NaN where a
+    /// window holds only NaNs.
     pub fn min(&self) -> Result<Series<f64>, BlackJackError>
     where
         T: Num + PartialOrd + Copy + ToPrimitive,
     {
-        // Pre-populate the beginning with NaNs up to window index
-        let mut vals = self.nans.clone();
-
-        // Calculate the remaining valid windows
-        // REMINDER: Using ArrayVeiw and re-implementing .mean() until Series has an ArrayView impl
-        vals.extend(
-            (0..self.series.len() + 1 - self.window)
-                .into_iter()
-                .map(
-                    |idx| match funcs::min(&self.series.values[idx..idx + self.window]) {
-                        Some(min) => Ok(min.to_f64().unwrap()),
-                        None => Err(BlackJackError::from("Failed to calculate min for window")),
-                    },
-                )
-                .collect::<Result<Vec<f64>, _>>()?,
-        );
-        Ok(Series::from_vec(vals))
+        Ok(self.reduce(RollingAgg::Min))
     }
 
-    /// Calculate a rolling max from the current instance.
+    /// Calculate a rolling max from the current instance, NaN where a
+    /// window holds only NaNs.
     pub fn max(&self) -> Result<Series<f64>, BlackJackError>
     where
         T: PartialOrd + Num + ToPrimitive + Copy,
     {
-        // Pre-populate the beginning with NaNs up to window index
-        let mut vals = self.nans.clone();
-
-        // Calculate the remaining valid windows
-        // REMINDER: Using ArrayVeiw and re-implementing .mean() until Series has an ArrayView impl
-        vals.extend(
-            (0..self.series.len() + 1 - self.window)
-                .into_iter()
-                .map(
-                    |idx| match funcs::max(&self.series.values[idx..idx + self.window]) {
-                        Some(max) => Ok(max.to_f64().unwrap()),
-                        None => Err(BlackJackError::from("Failed to calculate min for window")),
-                    },
-                )
-                .collect::<Result<Vec<f64>, _>>()?,
-        );
-        Ok(Series::from_vec(vals))
+        Ok(self.reduce(RollingAgg::Max))
+    }
+
+    /// Reduce each window independently with a single aggregation
+    fn reduce(&self, agg: RollingAgg) -> Series<f64>
+    where
+        T: ToPrimitive,
+    {
+        let vals = self
+            .window_ranges()
+            .into_par_iter()
+            .map(|range| {
+                range
+                    .and_then(|range| self.window_values(range))
+                    .map_or(f64::NAN, |window| agg.compute(&window))
+            })
+            .collect::<Vec<f64>>();
+        Series::from_vec(vals)
     }
 }
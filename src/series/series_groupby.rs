@@ -4,21 +4,62 @@ use std::iter::Sum;
 
 use crate::prelude::*;
 use num::*;
+use rayon::prelude::*;
 
 /// [`Series::groupby`]  result.
 /// Contains the split series by key
 #[derive(Clone)]
 pub struct SeriesGroupBy<T: BlackJackData> {
     groups: Vec<Series<T>>,
+    /// Positions of each group's values within the original series
+    positions: Vec<Vec<usize>>,
+    /// Name of the original series, given to aggregations of the groups
+    name: Option<String>,
 }
 
 impl<T> SeriesGroupBy<T>
 where
     T: BlackJackData,
 {
-    /// Create a new [`SeriesGroupBy`] from a `Vec<Series>`
+    /// Create a new [`SeriesGroupBy`] from a `Vec<Series>`, where the values
+    /// of the groups are taken to be laid out one after another.
     pub fn new(groups: Vec<Series<T>>) -> Self {
-        SeriesGroupBy { groups }
+        let mut start = 0;
+        let positions = groups
+            .iter()
+            .map(|group| {
+                start += group.len();
+                (start - group.len()..start).collect()
+            })
+            .collect();
+        SeriesGroupBy {
+            groups,
+            positions,
+            name: None,
+        }
+    }
+
+    /// Create a new [`SeriesGroupBy`] where `positions` holds the positions of each
+    /// group's values within the series it was grouped from, named `name`.
+    pub(crate) fn with_positions(
+        groups: Vec<Series<T>>,
+        positions: Vec<Vec<usize>>,
+        name: Option<String>,
+    ) -> Self {
+        SeriesGroupBy {
+            groups,
+            positions,
+            name,
+        }
+    }
+
+    /// Name an aggregation of the groups after the series which was grouped
+    fn named<A: BlackJackData>(&self, series: Series<A>) -> Series<A> {
+        let mut series = series;
+        if let Some(name) = &self.name {
+            series.set_name(name);
+        }
+        series
     }
 
     /// Apply an **aggregation** function to each [`Series`]
@@ -47,55 +88,149 @@ where
         T: Send,
     {
         let results = self.groups.into_iter().map(agg_func).collect::<Vec<T>>();
-        Series::from_vec(results)
+        let mut series = Series::from_vec(results);
+        if let Some(name) = &self.name {
+            series.set_name(name);
+        }
+        series
+    }
+
+    /// Apply a **transform** function to each [`Series`] in [`SeriesGroupBy`],
+    /// yielding one [`Series`] per group which may differ in length from the group.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1., 10., 3., 30.]);
+    /// let keys   = Series::from_vec(vec![0., 1., 0., 1.]);
+    ///
+    /// // z-score each group
+    /// let scored = series.groupby(&keys).apply_vec(|s: Series<f64>| {
+    ///     let (mean, std) = (s.mean().unwrap(), s.std(0.).unwrap());
+    ///     s.map(|v| (v - mean) / std)
+    /// });
+    ///
+    /// assert_eq!(scored.len(), 2);
+    /// assert_eq!(scored[0].values, vec![-1., 1.]);
+    /// assert_eq!(scored[1].values, vec![-1., 1.]);
+    /// ```
+    pub fn apply_vec<F>(self, func: F) -> Vec<Series<T>>
+    where
+        F: Fn(Series<T>) -> Series<T>,
+    {
+        self.groups.into_iter().map(func).collect()
+    }
+
+    /// Number each value within its group, counting from 0 in the order the values
+    /// appeared. The result is aligned with the series that was grouped.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1, 2, 3, 4, 5, 6]);
+    /// let keys   = Series::from_vec(vec![1, 2, 1, 1, 2, 3]);
+    ///
+    /// let counts = series.groupby(&keys).cumcount();
+    /// assert_eq!(counts.values, vec![0, 0, 1, 2, 1, 0]);
+    /// ```
+    pub fn cumcount(&self) -> Series<i64> {
+        let mut counts = vec![0; self.positions.iter().map(Vec::len).sum()];
+        for positions in self.positions.iter() {
+            for (count, position) in positions.iter().enumerate() {
+                counts[*position] = count as i64;
+            }
+        }
+        Series::from_vec(counts)
+    }
+
+    /// Take the first value of each [`Series`] group, groups without
+    /// any values are skipped.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1, 2, 3, 4, 5, 6]);
+    /// let keys   = Series::from_vec(vec![7, 8, 7, 8, 7, 9]);
+    ///
+    /// let grouped = series.groupby(&keys);
+    /// assert_eq!(grouped.first().values, vec![1, 2, 6]);
+    /// assert_eq!(grouped.last().values, vec![5, 4, 6]);
+    /// ```
+    pub fn first(&self) -> Series<T> {
+        self.named(Series::from_vec(
+            self.groups
+                .iter()
+                .filter_map(|group| group.values.first().cloned())
+                .collect(),
+        ))
+    }
+
+    /// Take the last value of each [`Series`] group, see [`SeriesGroupBy::first`]
+    pub fn last(&self) -> Series<T> {
+        self.named(Series::from_vec(
+            self.groups
+                .iter()
+                .filter_map(|group| group.values.last().cloned())
+                .collect(),
+        ))
     }
 
     /// Apply a `sum` aggregation to each [`Series`] group
     pub fn sum(&self) -> Series<T>
     where
-        T: Ord + Num + Sum + Copy,
+        T: Ord + Num + Sum + Copy + Sync,
     {
-        let mut results = vec![];
-        for group in &self.groups {
-            results.push(group.sum());
-        }
-        Series::from_vec(results)
+        let results = self
+            .groups
+            .par_iter()
+            .map(|group| group.sum())
+            .collect::<Vec<T>>();
+        self.named(Series::from_vec(results))
     }
 
     /// Apply a `min` aggregation to each [`Series`] group
     pub fn min(&self) -> Result<Series<T>, BlackJackError>
     where
-        T: PartialOrd + Num + ToPrimitive + Copy,
+        T: PartialOrd + Num + ToPrimitive + Copy + Sync,
     {
-        let mut results = vec![];
-        for group in &self.groups {
-            results.push(group.min()?);
-        }
-        Ok(Series::from_vec(results))
+        let results = self
+            .groups
+            .par_iter()
+            .map(|group| group.min())
+            .collect::<Result<Vec<T>, BlackJackError>>()?;
+        Ok(self.named(Series::from_vec(results)))
     }
 
     /// Apply a `max` aggregation to each [`Series`] group
     pub fn max(&self) -> Result<Series<T>, BlackJackError>
     where
-        T: PartialOrd + Num + Copy,
+        T: PartialOrd + Num + Copy + Sync,
     {
-        let mut results = vec![];
-        for group in &self.groups {
-            results.push(group.max()?);
-        }
-        Ok(Series::from_vec(results))
+        let results = self
+            .groups
+            .par_iter()
+            .map(|group| group.max())
+            .collect::<Result<Vec<T>, BlackJackError>>()?;
+        Ok(self.named(Series::from_vec(results)))
     }
 
     /// Apply a `max` aggregation to each [`Series`] group
     pub fn mean(&self) -> Result<Series<f64>, BlackJackError>
     where
-        for<'b> T: PartialOrd + Num + Sum + Copy + ToPrimitive + Sum<&'b T>,
+        for<'b> T: PartialOrd + Num + Sum + Copy + ToPrimitive + Sum<&'b T> + Sync,
     {
-        let mut results = vec![];
-        for group in &self.groups {
-            results.push(group.mean()?);
-        }
-        Ok(Series::from_vec(results))
+        let results = self
+            .groups
+            .par_iter()
+            .map(|group| group.mean())
+            .collect::<Result<Vec<f64>, BlackJackError>>()?;
+        Ok(self.named(Series::from_vec(results)))
     }
 
     /// Apply a `max` aggregation to each [`Series`] group, using either population or sample variance
@@ -103,12 +238,13 @@ where
     /// > Sample: `ddof` == 1_f64
     pub fn var(&self, ddof: f64) -> Result<Series<f64>, BlackJackError>
     where
-        T: Num + ToPrimitive,
+        T: Num + ToPrimitive + Sync,
     {
-        let mut results = vec![];
-        for group in &self.groups {
-            results.push(group.var(ddof)?);
-        }
-        Ok(Series::from_vec(results))
+        let results = self
+            .groups
+            .par_iter()
+            .map(|group| group.var(ddof))
+            .collect::<Result<Vec<f64>, BlackJackError>>()?;
+        Ok(self.named(Series::from_vec(results)))
     }
 }
@@ -0,0 +1,93 @@
+//! Lightweight contract checks on the values of a `Series`
+
+use std::fmt;
+
+use crate::prelude::*;
+
+/// A rule each value of a [`Series`] is checked against by [`Series::validate`]
+pub enum ValidationRule<T> {
+    /// Values must not be null, ie. `NaN`
+    NotNull,
+
+    /// Values must not be less than this minimum
+    Min(T),
+
+    /// Values must not be greater than this maximum
+    Max(T),
+
+    /// Values must satisfy the predicate, described by the name given
+    Predicate(String, Box<dyn Fn(&T) -> bool>),
+}
+
+impl<T: PartialOrd> ValidationRule<T> {
+    fn is_violated_by(&self, value: &T) -> bool {
+        match self {
+            ValidationRule::NotNull => value.partial_cmp(value).is_none(),
+            ValidationRule::Min(min) => value < min,
+            ValidationRule::Max(max) => value > max,
+            ValidationRule::Predicate(_, predicate) => !predicate(value),
+        }
+    }
+}
+
+/// A violation of a [`ValidationRule`] found by [`Series::validate`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    /// Position of the offending value in the series
+    pub position: usize,
+
+    /// Description of the rule which was violated
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "position {}: {}", self.position, self.message)
+    }
+}
+
+impl<T> Series<T>
+where
+    T: BlackJackData + PartialOrd,
+{
+    /// Check every value against `rules`, returning each violation found in
+    /// order of position; an empty result means the series is valid.
+    ///
+    /// ## Example
+    /// ```
+    /// use blackjack::prelude::*;
+    ///
+    /// let series = Series::from_vec(vec![1., f64::NAN, 12.]);
+    /// let rules = vec![
+    ///     ValidationRule::NotNull,
+    ///     ValidationRule::Min(0.),
+    ///     ValidationRule::Max(10.),
+    /// ];
+    ///
+    /// let errors = series.validate(&rules);
+    /// assert_eq!(errors.len(), 2);
+    /// assert_eq!(errors[0].position, 1);
+    /// assert_eq!(errors[1].position, 2);
+    /// ```
+    pub fn validate(&self, rules: &[ValidationRule<T>]) -> Vec<ValidationError> {
+        let mut errors = vec![];
+        for (position, value) in self.values.iter().enumerate() {
+            for rule in rules.iter().filter(|rule| rule.is_violated_by(value)) {
+                let message = match rule {
+                    ValidationRule::NotNull => "value is null".to_string(),
+                    ValidationRule::Min(min) => {
+                        format!("value {} is less than minimum {}", value, min)
+                    }
+                    ValidationRule::Max(max) => {
+                        format!("value {} is greater than maximum {}", value, max)
+                    }
+                    ValidationRule::Predicate(name, _) => {
+                        format!("value {} fails predicate '{}'", value, name)
+                    }
+                };
+                errors.push(ValidationError { position, message });
+            }
+        }
+        errors
+    }
+}
@@ -14,6 +14,8 @@ pub enum GenericSeriesContainer {
     F32(Series<f32>),
     /// Hold `String` type series
     STRING(Series<String>),
+    /// Hold `bool` type series
+    BOOL(Series<bool>),
 }
 
 impl GenericSeriesContainer {
@@ -26,10 +28,45 @@ impl GenericSeriesContainer {
             GenericSeriesContainer::I32(series) => series.into_type::<String>().unwrap().into_vec(),
             GenericSeriesContainer::F32(series) => series.into_type::<String>().unwrap().into_vec(),
             GenericSeriesContainer::STRING(series) => series.into_vec(),
+            GenericSeriesContainer::BOOL(series) => {
+                series.into_type::<String>().unwrap().into_vec()
+            }
+        }
+    }
+
+    /// Drop positions of the contained `Series`, see [`Series::drop_positions`]
+    pub fn drop_positions(&mut self, positions: &[usize]) {
+        let positions = positions.iter().cloned();
+        match self {
+            GenericSeriesContainer::I64(series) => series.drop_positions(positions),
+            GenericSeriesContainer::F64(series) => series.drop_positions(positions),
+            GenericSeriesContainer::I32(series) => series.drop_positions(positions),
+            GenericSeriesContainer::F32(series) => series.drop_positions(positions),
+            GenericSeriesContainer::STRING(series) => series.drop_positions(positions),
+            GenericSeriesContainer::BOOL(series) => series.drop_positions(positions),
+        }
+    }
+
+    /// Build a new container holding only the values at `positions`
+    pub(crate) fn select_positions(&self, positions: &[usize]) -> GenericSeriesContainer {
+        match self {
+            GenericSeriesContainer::I64(series) => series.select_positions(positions).into(),
+            GenericSeriesContainer::F64(series) => series.select_positions(positions).into(),
+            GenericSeriesContainer::I32(series) => series.select_positions(positions).into(),
+            GenericSeriesContainer::F32(series) => series.select_positions(positions).into(),
+            GenericSeriesContainer::STRING(series) => series.select_positions(positions).into(),
+            GenericSeriesContainer::BOOL(series) => series.select_positions(positions).into(),
         }
     }
 }
 
+impl_generic_series_container_from!(i64, I64);
+impl_generic_series_container_from!(f64, F64);
+impl_generic_series_container_from!(i32, I32);
+impl_generic_series_container_from!(f32, F32);
+impl_generic_series_container_from!(String, STRING);
+impl_generic_series_container_from!(bool, BOOL);
+
 /// Serialized version of `Series<T>`, enabling storage inside a homogeneous container
 /// where metadata is stored and data is stored in byte/compressed format.
 #[derive(Debug, Clone)]
@@ -47,7 +84,7 @@ impl<T: BlackJackData> From<&Series<T>> for SeriesMeta {
         SeriesMeta {
             name: series.name().unwrap(),
             len: series.len(),
-            dtype: series.dtype().unwrap(),
+            dtype: T::dtype_of(),
         }
     }
 }
@@ -8,31 +8,67 @@ use crate::prelude::*;
 
 /// Trait dictates the supported primitives for use in [`Series`] structs.
 pub trait BlackJackData: Serialize + Debug + ToString + Clone + Send + Display {
+    /// Return the [`DType`] of this type, without needing a value of it;
+    /// ie. for an empty [`Series`].
+    fn dtype_of() -> DType;
+
     /// Return the current [`DType`] for this type.
-    fn dtype(&self) -> DType;
+    fn dtype(&self) -> DType {
+        Self::dtype_of()
+    }
+
+    /// Bytes owned on the heap by this value, beyond its `size_of`; zero for plain numerics.
+    fn heap_size(&self) -> usize {
+        0
+    }
+
+    /// Value representing missing data, if this type has one; `NaN` for floats.
+    fn missing() -> Option<Self> {
+        None
+    }
 }
 impl BlackJackData for f64 {
-    fn dtype(&self) -> DType {
+    fn dtype_of() -> DType {
         DType::F64
     }
+    fn missing() -> Option<Self> {
+        Some(f64::NAN)
+    }
 }
 impl BlackJackData for i64 {
-    fn dtype(&self) -> DType {
+    fn dtype_of() -> DType {
         DType::I64
     }
 }
 impl BlackJackData for f32 {
-    fn dtype(&self) -> DType {
+    fn dtype_of() -> DType {
         DType::F32
     }
+    fn missing() -> Option<Self> {
+        Some(f32::NAN)
+    }
 }
 impl BlackJackData for i32 {
-    fn dtype(&self) -> DType {
+    fn dtype_of() -> DType {
         DType::I32
     }
 }
 impl BlackJackData for String {
-    fn dtype(&self) -> DType {
+    fn dtype_of() -> DType {
         DType::STRING
     }
+    fn heap_size(&self) -> usize {
+        self.len()
+    }
+}
+impl BlackJackData for bool {
+    fn dtype_of() -> DType {
+        DType::BOOL
+    }
+}
+
+/// Trait for converting a [`Row`] into a typed struct; see [`DataFrame::collect_rows`]
+pub trait FromRow: Sized {
+    /// Create `Self` from a `Row`, typically extracting each field by column name.
+    fn from_row(row: &Row<'_>) -> Result<Self, BlackJackError>;
 }
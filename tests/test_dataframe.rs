@@ -1,3 +1,4 @@
+#[macro_use]
 extern crate blackjack;
 extern crate tempfile;
 
@@ -172,3 +173,885 @@ fn test_get_column_by_name() {
         .expect("Unable to find column named 'test-series'");
     assert_eq!(series_ref, &series_clone);
 }
+
+#[test]
+fn test_read_csv_na_values() {
+    let path = format!("{}/tests/data/na_csv.csv", env!("CARGO_MANIFEST_DIR"));
+    let df = Reader::new(&path)
+        .na_values(vec!["NA".to_string()])
+        .read()
+        .unwrap();
+
+    // Integer column holding 'NA' becomes a float column with a NaN
+    let col1: &Series<f32> = df.get_column("col1").unwrap();
+    assert!(col1[1].is_nan());
+    assert_eq!(col1[2], 3.0);
+
+    let col2: &Series<f32> = df.get_column("col2").unwrap();
+    assert!(col2[2].is_nan());
+
+    // String columns are left as they are
+    let col3: &Series<String> = df.get_column("col3").unwrap();
+    assert_eq!(col3[3], "NA".to_string());
+}
+
+#[test]
+fn test_read_infer_delimiter() {
+    for file in &["basic_csv.csv", "basic_tsv.tsv"] {
+        let path = format!("{}/tests/data/{}", env!("CARGO_MANIFEST_DIR"), file);
+        let df = Reader::new(&path).infer_delimiter().read().unwrap();
+
+        let mut col_names = df.columns().collect::<Vec<&str>>();
+        col_names.sort();
+        assert_eq!(col_names, vec!["col1", "col2", "col3"]);
+
+        let col2: &Series<i32> = df.get_column("col2").unwrap();
+        assert_eq!(col2.sum(), 15);
+    }
+}
+
+#[test]
+fn test_read_stream() {
+    let path = format!("{}/tests/data/medium_csv.csv", env!("CARGO_MANIFEST_DIR"));
+
+    let rows = Reader::new(&path)
+        .stream()
+        .unwrap()
+        .collect::<Result<Vec<OwnedRow>, _>>()
+        .unwrap();
+    assert_eq!(rows.len(), 1000);
+
+    let col2_sum: i32 = rows
+        .iter()
+        .map(|row| match row["col2"] {
+            DataElement::I32(val) => val,
+            _ => panic!("Expected an i32 element"),
+        })
+        .sum();
+    assert_eq!(col2_sum, 3000);
+}
+
+#[test]
+fn test_write_ref() {
+    let mut df = DataFrame::new();
+    df.add_column(Series::arange(0, 10)).unwrap();
+
+    let tdir = tempdir().unwrap();
+    let writer = Writer::new(&tdir.path().join("out.csv").to_str().unwrap());
+
+    // Writing by reference leaves the frame usable, so we can write it again
+    assert!(writer.write_ref(&df).is_ok());
+    assert!(writer.write_ref(&df).is_ok());
+
+    let path = tdir.path().join("out.csv");
+    let new_df = Reader::new(&path.to_str().unwrap()).read().unwrap();
+    assert_eq!(new_df.len(), df.len());
+}
+
+#[test]
+fn test_read_invalid_record() {
+    let tdir = tempdir().unwrap();
+    let path = tdir.path().join("ragged.csv");
+    std::fs::write(&path, "col1,col2\n1,2\n3\n").unwrap();
+
+    // Records which can't be read are reported rather than skipped
+    assert!(Reader::new(&path.to_str().unwrap()).read().is_err());
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_read_write_parquet() {
+    let mut df = DataFrame::new();
+    df.add_column(Series::from_vec(vec![1_f64, 2., 3.]))
+        .unwrap();
+    df.add_column(Series::from_vec(vec![1_i64, 2, 3])).unwrap();
+    df.add_column(Series::from_vec(vec![1_f32, 2., 3.]))
+        .unwrap();
+    df.add_column(Series::from_vec(vec![1_i32, 2, 3])).unwrap();
+    df.add_column(Series::from_vec(vec![
+        "a".to_string(),
+        "b".to_string(),
+        "c".to_string(),
+    ]))
+    .unwrap();
+
+    let tdir = tempdir().unwrap();
+    let path = tdir.path().join("out.parquet");
+    let path = path.to_str().unwrap();
+
+    Writer::new(&path).write_parquet(&df).unwrap();
+    let new_df = Reader::new(&path).read_parquet().unwrap();
+
+    assert_eq!(new_df.len(), 3);
+    assert_eq!(new_df.n_columns(), 5);
+
+    let col: &Series<f64> = new_df.get_column("col_0").unwrap();
+    assert_eq!(col.values, vec![1., 2., 3.]);
+    let col: &Series<i64> = new_df.get_column("col_1").unwrap();
+    assert_eq!(col.values, vec![1, 2, 3]);
+    let col: &Series<f32> = new_df.get_column("col_2").unwrap();
+    assert_eq!(col.values, vec![1., 2., 3.]);
+    let col: &Series<i32> = new_df.get_column("col_3").unwrap();
+    assert_eq!(col.values, vec![1, 2, 3]);
+    let col: &Series<String> = new_df.get_column("col_4").unwrap();
+    assert_eq!(col.values, vec!["a", "b", "c"]);
+
+    // Files without rows keep their columns' types
+    df.try_drop_positions(0..3).unwrap();
+    Writer::new(&path).write_parquet(&df).unwrap();
+    let new_df = Reader::new(&path).read_parquet().unwrap();
+
+    assert_eq!(new_df.len(), 0);
+    assert_eq!(new_df.n_columns(), 5);
+    assert!(new_df.get_column::<i64>("col_1").unwrap().is_empty());
+    assert!(new_df.get_column_infer("col_4").is_some());
+}
+
+#[test]
+fn test_from_columns() {
+    let df = DataFrame::from_columns(vec![
+        (
+            "col1".to_string(),
+            GenericSeriesContainer::from(vec![1, 2, 3]),
+        ),
+        (
+            "col2".to_string(),
+            GenericSeriesContainer::from(Series::from_vec(vec![
+                "a".to_string(),
+                "b".to_string(),
+                "c".to_string(),
+            ])),
+        ),
+    ])
+    .unwrap();
+    assert_eq!(df.len(), 3);
+    assert_eq!(df.get_column::<i32>("col1").unwrap().values, vec![1, 2, 3]);
+
+    // Lengths must match
+    let res = DataFrame::from_columns(vec![
+        (
+            "col1".to_string(),
+            GenericSeriesContainer::from(vec![1, 2, 3]),
+        ),
+        (
+            "col2".to_string(),
+            GenericSeriesContainer::from(vec![1., 2.]),
+        ),
+    ]);
+    assert!(res.is_err());
+
+    // Names must be unique
+    let res = dataframe!["col1" => vec![1, 2], "col1" => vec![3, 4]];
+    assert!(res.is_err());
+
+    let df = dataframe!["col1" => vec![1_i64, 2], "col2" => vec![1_f32, 2.]].unwrap();
+    assert_eq!(df.n_columns(), 2);
+}
+
+#[test]
+fn test_df_drop_positions_mixed_columns() {
+    let mut df = dataframe![
+        "ints" => vec![0, 1, 2, 3, 4],
+        "floats" => vec![0., 1., 2., 3., 4.],
+        "strings" => vec!["a", "b", "c", "d", "e"]
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<String>>(),
+    ]
+    .unwrap();
+
+    df.drop_positions(vec![1, 3].into_iter());
+
+    assert_eq!(df.len(), 3);
+    assert_eq!(df.get_column::<i32>("ints").unwrap().values, vec![0, 2, 4]);
+    assert_eq!(
+        df.get_column::<f64>("floats").unwrap().values,
+        vec![0., 2., 4.]
+    );
+    assert_eq!(
+        df.get_column::<String>("strings").unwrap().values,
+        vec!["a".to_string(), "c".to_string(), "e".to_string()]
+    );
+    assert_eq!(
+        df.columns()
+            .collect::<std::collections::HashSet<&str>>()
+            .len(),
+        3
+    );
+}
+
+#[test]
+fn test_df_bool_column() {
+    let mut df = dataframe!["flags" => vec![true, false, true], "ints" => vec![1, 2, 3]].unwrap();
+    assert_eq!(
+        df.get_column::<bool>("flags").unwrap().values,
+        vec![true, false, true]
+    );
+
+    df.filter_by_row(|row| row["flags"] == Datum::BOOL(&false));
+    assert_eq!(df.len(), 2);
+    assert_eq!(df.get_column::<i32>("ints").unwrap().values, vec![1, 3]);
+}
+
+#[test]
+fn test_df_filter_mask() {
+    let mut df = dataframe![
+        "col1" => vec![1, 7, 3, 9],
+        "col2" => vec![0.1, 0.7, 0.3, 0.9],
+    ]
+    .unwrap();
+
+    let mask = df.get_column::<i32>("col1").unwrap().gt(5);
+    df.filter_mask(&mask).unwrap();
+
+    assert_eq!(df.len(), 2);
+    assert_eq!(df.get_column::<i32>("col1").unwrap().values, vec![7, 9]);
+    assert_eq!(df.get_column::<f64>("col2").unwrap().values, vec![0.7, 0.9]);
+
+    // Mask must match the length of the frame
+    assert!(df.filter_mask(&mask).is_err());
+}
+
+#[test]
+fn test_df_into_rows() {
+    let df = dataframe![
+        "ints" => vec![1_i64, 2, 3],
+        "strings" => vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        "flags" => vec![true, false, true],
+    ]
+    .unwrap();
+
+    let rows = df.into_rows();
+    assert_eq!(rows.len(), 3);
+
+    // Rows can be moved to another thread
+    let handle = std::thread::spawn(move || rows);
+    let rows = handle.join().unwrap();
+
+    assert_eq!(rows[0]["ints"], DataElement::I64(1));
+    assert_eq!(rows[1]["strings"], DataElement::STR("b".to_string()));
+    assert_eq!(rows[2]["flags"], DataElement::BOOL(true));
+    assert_eq!(
+        rows[2]
+            .data
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["ints", "strings", "flags"]
+    );
+}
+
+#[test]
+fn test_df_column_as_f64() {
+    let df = dataframe![
+        "ints" => vec![1, 2, 3],
+        "floats" => vec![0.5_f32, 1.5, 2.5],
+        "strings" => vec!["a".to_string(), "b".to_string(), "c".to_string()],
+    ]
+    .unwrap();
+
+    assert_eq!(df.column_as_f64("ints"), Some(vec![1., 2., 3.]));
+    assert_eq!(df.column_as_f64("floats"), Some(vec![0.5, 1.5, 2.5]));
+    assert_eq!(df.column_as_f64("strings"), None);
+    assert_eq!(df.column_as_f64("missing"), None);
+}
+
+#[test]
+fn test_read_float_precision() {
+    let tdir = tempdir().unwrap();
+    let path = tdir.path().join("precise.csv");
+    std::fs::write(&path, "col1\n0.123456789012345\n1234567.891234567\n").unwrap();
+    let path = path.to_str().unwrap();
+
+    // Default parses into f32, losing precision
+    let df = Reader::new(&path).read().unwrap();
+    let col1: &Series<f32> = df.get_column("col1").unwrap();
+    assert_ne!(col1[1] as f64, 1234567.891234567);
+
+    let df = Reader::new(&path)
+        .float_precision(DType::F64)
+        .read()
+        .unwrap();
+    let col1: &Series<f64> = df.get_column("col1").unwrap();
+    assert_eq!(col1.values, vec![0.123456789012345, 1234567.891234567]);
+}
+
+#[test]
+fn test_df_train_test_split() {
+    let df = dataframe![
+        "id" => (0..100).collect::<Vec<i32>>(),
+        "name" => (0..100).map(|v| v.to_string()).collect::<Vec<String>>(),
+    ]
+    .unwrap();
+
+    let (train, test) = df.train_test_split(0.25, Some(0));
+    assert_eq!(train.len(), 75);
+    assert_eq!(test.len(), 25);
+
+    // Together the frames cover every row exactly once
+    let train_ids = train.get_column::<i32>("id").unwrap();
+    let test_ids = test.get_column::<i32>("id").unwrap();
+    let mut ids = train_ids
+        .values
+        .iter()
+        .chain(test_ids.values.iter())
+        .cloned()
+        .collect::<Vec<i32>>();
+    ids.sort();
+    assert_eq!(ids, (0..100).collect::<Vec<i32>>());
+
+    // Rows stay intact across columns
+    let names = test.get_column::<String>("name").unwrap();
+    assert!(test_ids
+        .values
+        .iter()
+        .zip(&names.values)
+        .all(|(id, name)| id.to_string() == *name));
+
+    // The same seed gives the same split
+    let df = dataframe!["id" => (0..100).collect::<Vec<i32>>()].unwrap();
+    let (_, test_again) = df.train_test_split(0.25, Some(0));
+    assert_eq!(
+        test_again.get_column::<i32>("id").unwrap().values,
+        test_ids.values
+    );
+}
+
+#[test]
+fn test_df_cov() {
+    let df = dataframe![
+        "a" => vec![1, 2, 3, 4, 5],
+        "b" => vec![2.5_f32, 0.5, 4.0, 1.0, 3.0],
+        "c" => vec![10_i64, 8, 6, 4, 2],
+        "d" => vec!["a", "b", "c", "d", "e"].into_iter().map(String::from).collect::<Vec<String>>(),
+    ]
+    .unwrap();
+
+    let cov = df.cov(1.);
+
+    // String columns are left out
+    let mut names = cov.columns().collect::<Vec<&str>>();
+    names.sort();
+    assert_eq!(names, vec!["a", "b", "c"]);
+    assert_eq!(cov.len(), 3);
+
+    // The diagonal holds the variance of each column
+    let a: &Series<f64> = cov.get_column("a").unwrap();
+    let b: &Series<f64> = cov.get_column("b").unwrap();
+    let c: &Series<f64> = cov.get_column("c").unwrap();
+    assert_eq!(a[0], df.get_column::<i32>("a").unwrap().var(1.).unwrap());
+    assert_eq!(b[1], df.get_column::<f32>("b").unwrap().var(1.).unwrap());
+    assert_eq!(c[2], df.get_column::<i64>("c").unwrap().var(1.).unwrap());
+
+    // And is symmetric
+    assert_eq!(a[2], c[0]);
+    assert_eq!(a[2], -5.);
+}
+
+#[test]
+fn test_df_groupby_column() {
+    let df = dataframe![
+        "key" => vec![1, 2, 1, 2, 1],
+        "values" => vec![1, 2, 3, 4, 5],
+    ]
+    .unwrap();
+
+    let grouped = df.groupby_column::<i32>("key").unwrap().sum();
+    assert_eq!(grouped.len(), 2);
+    assert_eq!(grouped.n_columns(), 2);
+
+    // The key column holds the group labels rather than being summed
+    let key: &Series<i32> = grouped.get_column("key").unwrap();
+    assert_eq!(key.values, vec![1, 2]);
+
+    // Missing columns, or columns of another type, can't be grouped by
+    assert!(df.groupby_column::<i32>("missing").is_err());
+    assert!(df.groupby_column::<f64>("key").is_err());
+
+    // Columns of another type than the key are skipped, the rest keep their order
+    let df = dataframe![
+        "key" => vec![1, 2, 1, 2, 1],
+        "price" => vec![1.5, 2.5, 3.5, 4.5, 5.5],
+        "b" => vec![1, 2, 3, 4, 5],
+        "a" => vec![10, 20, 30, 40, 50],
+    ]
+    .unwrap();
+    let grouped = df.groupby_column::<i32>("key").unwrap().sum();
+    assert_eq!(grouped.n_columns(), 3);
+    let b: &Series<i32> = grouped.get_column("b").unwrap();
+    assert_eq!(b.values, vec![9, 6]);
+    let a: &Series<i32> = grouped.get_column("a").unwrap();
+    assert_eq!(a.values, vec![90, 60]);
+    let row = grouped.iter_rows().next().unwrap();
+    assert_eq!(
+        row.data.iter().map(|e| e.name.as_str()).collect::<Vec<_>>(),
+        vec!["key", "b", "a"]
+    );
+
+    // Names which look like default column names are kept as they are
+    let df = dataframe![
+        "col_1" => vec![1, 2, 1],
+        "col_0" => vec![1, 2, 3],
+    ]
+    .unwrap();
+    let grouped = df.groupby_column::<i32>("col_1").unwrap().sum();
+    let summed: &Series<i32> = grouped.get_column("col_0").unwrap();
+    assert_eq!(summed.values, vec![4, 2]);
+}
+
+#[test]
+fn test_df_add_lag() {
+    let mut df = dataframe!["price" => vec![10, 11, 13, 12]].unwrap();
+
+    df.add_lag("price", 1, "price_lag_1").unwrap();
+    let lagged: &Series<f64> = df.get_column("price_lag_1").unwrap();
+    assert!(lagged[0].is_nan());
+    assert_eq!(lagged[1..4].to_vec(), vec![10., 11., 13.]);
+
+    // Lags longer than the frame are all NaN
+    df.add_lag("price", 10, "price_lag_10").unwrap();
+    let lagged: &Series<f64> = df.get_column("price_lag_10").unwrap();
+    assert!(lagged.values.iter().all(|v| v.is_nan()));
+
+    assert!(df.add_lag("missing", 1, "lag").is_err());
+    assert!(df.add_lag("price", 1, "price_lag_1").is_err());
+}
+
+#[test]
+fn test_df_add_diff() {
+    let mut df = dataframe!["price" => vec![10.5_f32, 11., 13., 12.5]].unwrap();
+
+    df.add_diff("price", 2, "price_diff_2").unwrap();
+    let diff: &Series<f64> = df.get_column("price_diff_2").unwrap();
+    assert!(diff[0].is_nan());
+    assert!(diff[1].is_nan());
+    assert_eq!(diff[2..4].to_vec(), vec![2.5, 1.5]);
+
+    assert!(df.add_diff("missing", 1, "diff").is_err());
+}
+
+#[test]
+fn test_df_dropna() {
+    let nan = f64::NAN;
+    let build = || {
+        dataframe![
+            "id" => vec![0, 1, 2, 3, 4],
+            "a" => vec![1., nan, 3., nan, 5.],
+            "b" => vec![1_f32, 2., f32::NAN, f32::NAN, 5.],
+        ]
+        .unwrap()
+    };
+
+    let mut df = build();
+    df.dropna(DropHow::Any);
+    assert_eq!(df.get_column::<i32>("id").unwrap().values, vec![0, 4]);
+
+    let mut df = build();
+    df.dropna(DropHow::All);
+    assert_eq!(df.get_column::<i32>("id").unwrap().values, vec![0, 1, 2, 4]);
+
+    // Without float columns nothing is dropped
+    let mut df = dataframe!["id" => vec![0, 1, 2]].unwrap();
+    df.dropna(DropHow::All);
+    assert_eq!(df.len(), 3);
+}
+
+#[test]
+fn test_df_fillna() {
+    let mut df = dataframe![
+        "ints" => vec![1, 2, 3],
+        "f64s" => vec![f64::NAN, 2., f64::NAN],
+        "f32s" => vec![1_f32, f32::NAN, 3.],
+        "strs" => vec!["NaN".to_string(), "b".to_string(), "c".to_string()],
+    ]
+    .unwrap();
+
+    df.fillna(0.);
+    assert_eq!(
+        df.get_column::<f64>("f64s").unwrap().values,
+        vec![0., 2., 0.]
+    );
+    assert_eq!(
+        df.get_column::<f32>("f32s").unwrap().values,
+        vec![1., 0., 3.]
+    );
+
+    // Other columns are untouched
+    assert_eq!(df.get_column::<i32>("ints").unwrap().values, vec![1, 2, 3]);
+    assert_eq!(
+        df.get_column::<String>("strs").unwrap()[0],
+        "NaN".to_string()
+    );
+
+    assert!(df.fillna_column("ints", 0.).is_err());
+    assert!(df.fillna_column("missing", 0.).is_err());
+}
+
+#[test]
+fn test_df_astype_column() {
+    let mut df = dataframe![
+        "floats" => vec![1_f32, 2., 3.],
+        "strings" => vec!["4".to_string(), "x".to_string(), "6".to_string()],
+    ]
+    .unwrap();
+
+    df.astype_column::<i32>("floats").unwrap();
+    assert_eq!(
+        df.get_column::<i32>("floats").unwrap().values,
+        vec![1, 2, 3]
+    );
+    assert!(df.get_column::<f32>("floats").is_none());
+
+    // Rows see the new dtype
+    let row = df.iter_rows().next().unwrap();
+    assert!(row["floats"] == Datum::I32(&1));
+
+    // Failed conversions leave the column as it was
+    assert!(df.astype_column::<i64>("strings").is_err());
+    assert_eq!(df.get_column::<String>("strings").unwrap().len(), 3);
+
+    assert!(df.astype_column::<i64>("missing").is_err());
+
+    // Empty columns are converted too
+    let mut df = dataframe!["floats" => vec![1_f32, 2.]].unwrap();
+    df.try_drop_positions(0..2).unwrap();
+    df.astype_column::<String>("floats").unwrap();
+    assert!(df.get_column::<String>("floats").unwrap().is_empty());
+    assert!(df.get_column_infer("floats").is_some());
+}
+
+#[test]
+fn test_df_reorder_columns() {
+    let mut df = dataframe![
+        "a" => vec![1, 2],
+        "b" => vec![3, 4],
+        "c" => vec![5, 6],
+    ]
+    .unwrap();
+
+    df.reorder_columns(&["c", "a", "b"]).unwrap();
+    let row = df.iter_rows().next().unwrap();
+    let names = row
+        .data
+        .iter()
+        .map(|e| e.name.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(names, vec!["c", "a", "b"]);
+
+    // Unknown, repeated or missing names leave the order as it was
+    assert!(df.reorder_columns(&["c", "a", "x"]).is_err());
+    assert!(df.reorder_columns(&["c", "c", "a"]).is_err());
+    assert!(df.reorder_columns(&["c", "a"]).is_err());
+
+    let row = df.iter_rows().next().unwrap();
+    assert_eq!(row.data[0].name, "c".to_string());
+}
+
+#[test]
+fn test_write_column_alignment() {
+    let mut df = dataframe![
+        "a" => vec![1, 2],
+        "b" => vec![10, 20],
+        "c" => vec![100, 200],
+        "d" => vec!["x".to_string(), "y".to_string()],
+    ]
+    .unwrap();
+    df.reorder_columns(&["d", "c", "a", "b"]).unwrap();
+
+    let tdir = tempdir().unwrap();
+    let path = tdir.path().join("aligned.csv");
+    Writer::new(&path.to_str().unwrap()).write_ref(&df).unwrap();
+
+    // Header follows the frame's column order
+    let contents = std::fs::read_to_string(&path).unwrap();
+    assert!(contents.starts_with("d,c,a,b"));
+
+    // And each header labels its own data
+    let new_df = Reader::new(&path.to_str().unwrap()).read().unwrap();
+    assert_eq!(new_df.get_column::<i32>("a").unwrap().values, vec![1, 2]);
+    assert_eq!(new_df.get_column::<i32>("b").unwrap().values, vec![10, 20]);
+    assert_eq!(
+        new_df.get_column::<i32>("c").unwrap().values,
+        vec![100, 200]
+    );
+    assert_eq!(
+        new_df.get_column::<String>("d").unwrap().values,
+        vec!["x".to_string(), "y".to_string()]
+    );
+}
+
+#[test]
+fn test_df_concat_columns() {
+    let mut df = dataframe![
+        "a" => vec![1, 2, 3],
+        "b" => vec![1.5, 2.5, 3.5],
+    ]
+    .unwrap();
+    let other = dataframe![
+        "c" => vec![10_i64, 20, 30],
+        "d" => vec!["x".to_string(), "y".to_string(), "z".to_string()],
+        "e" => vec![true, false, true],
+    ]
+    .unwrap();
+
+    df.concat_columns(other).unwrap();
+    assert_eq!(df.n_columns(), 5);
+    assert_eq!(df.len(), 3);
+    assert_eq!(df.get_column::<i64>("c").unwrap().values, vec![10, 20, 30]);
+    assert_eq!(
+        df.get_column::<bool>("e").unwrap().values,
+        vec![true, false, true]
+    );
+
+    // Name collisions are errors unless a suffix is given
+    let other = dataframe!["a" => vec![4, 5, 6]].unwrap();
+    assert!(df.concat_columns(other).is_err());
+    assert_eq!(df.n_columns(), 5);
+
+    let other = dataframe!["a" => vec![4, 5, 6]].unwrap();
+    df.concat_columns_with_suffix(other, "_other").unwrap();
+    assert_eq!(
+        df.get_column::<i32>("a_other").unwrap().values,
+        vec![4, 5, 6]
+    );
+
+    // As are length mismatches
+    let other = dataframe!["f" => vec![1, 2]].unwrap();
+    assert!(df.concat_columns(other).is_err());
+}
+
+#[test]
+fn test_df_info() {
+    let df = dataframe![
+        "ints" => vec![1, 2, 3],
+        "floats" => vec![1.5, f64::NAN, 3.5],
+        "strings" => vec!["a".to_string(), "b".to_string(), "c".to_string()],
+    ]
+    .unwrap();
+
+    let info = df.info();
+    assert!(info.starts_with("3 rows, 3 columns"));
+    assert!(info.contains("ints     I32  3 non-null"));
+    assert!(info.contains("floats   F64  2 non-null"));
+    assert!(info.contains("strings  STRING  3 non-null"));
+}
+
+#[test]
+fn test_df_non_null_counts() {
+    let df = dataframe![
+        "f64s" => vec![1.5, f64::NAN, f64::NAN, 4.5],
+        "f32s" => vec![f32::NAN, 2.5, 3.5, 4.5],
+        "ints" => vec![1, 2, 3, 4],
+    ]
+    .unwrap();
+
+    let counts = df.non_null_counts();
+    assert_eq!(counts.len(), 3);
+    assert_eq!(counts["f64s"], 2);
+    assert_eq!(counts["f32s"], 3);
+    assert_eq!(counts["ints"], 4);
+}
+
+#[test]
+fn test_df_validate_schema() {
+    let df = dataframe![
+        "id" => vec![1, 2, 3],
+        "price" => vec![1.5, 2.5, 3.5],
+    ]
+    .unwrap();
+
+    let schema = vec![
+        ("id".to_string(), DType::I32),
+        ("price".to_string(), DType::F64),
+    ];
+    assert!(df.validate_schema(&schema).is_ok());
+
+    // A missing column and one of the wrong dtype are both reported
+    let schema = vec![
+        ("id".to_string(), DType::I64),
+        ("price".to_string(), DType::F64),
+        ("name".to_string(), DType::STRING),
+    ];
+    match df.validate_schema(&schema) {
+        Err(BlackJackError::ValueError(msg)) => {
+            assert!(msg.contains("column 'id' has dtype I32, expected I64"));
+            assert!(msg.contains("column 'name' is missing"));
+        }
+        _ => panic!("Expected schema validation to fail"),
+    }
+
+    // As are columns which aren't expected
+    let schema = vec![("id".to_string(), DType::I32)];
+    assert!(df.validate_schema(&schema).is_err());
+}
+
+#[test]
+fn test_series_to_csv() {
+    let tdir = tempdir().unwrap();
+    let path = tdir.path().join("series.csv");
+    let path = path.to_str().unwrap();
+
+    let mut series = Series::arange(0, 5);
+    series.set_name("values");
+    series.to_csv(path).unwrap();
+
+    let df = Reader::new(&path).read().unwrap();
+    assert_eq!(df.n_columns(), 1);
+    assert_eq!(df.get_column::<i32>("values").unwrap(), &series);
+
+    // Unnamed series get a placeholder header
+    Series::arange(0, 5).to_csv(path).unwrap();
+    let df = Reader::new(&path).read().unwrap();
+    assert_eq!(
+        df.get_column::<i32>("<NA>").unwrap().values,
+        vec![0, 1, 2, 3, 4]
+    );
+}
+
+#[test]
+fn test_series_read_csv_column() {
+    let path = format!("{}/tests/data/medium_csv.csv", env!("CARGO_MANIFEST_DIR"));
+
+    match Series::read_csv_column(&path, "col2").unwrap() {
+        GenericSeriesContainer::I32(series) => {
+            assert_eq!(series.name(), Some("col2".to_string()));
+            assert_eq!(series.len(), 1000);
+            assert_eq!(series.sum(), 3000);
+        }
+        _ => panic!("Expected col2 to be read as i32"),
+    }
+
+    assert!(Series::read_csv_column(&path, "missing").is_err());
+}
+
+#[test]
+fn test_read_usecols() {
+    let path = format!("{}/tests/data/basic_csv.csv", env!("CARGO_MANIFEST_DIR"));
+
+    // Order given doesn't matter, columns keep the order of the file
+    let df = Reader::new(&path)
+        .usecols(vec!["col3".to_string(), "col1".to_string()])
+        .read()
+        .unwrap();
+    assert_eq!(df.n_columns(), 2);
+    assert_eq!(df.len(), 5);
+    assert!(df.get_column_infer("col2").is_none());
+    assert_eq!(df.get_column::<f32>("col1").unwrap().sum(), 15.);
+    assert_eq!(
+        df.iter_rows().next().unwrap().data[1].name,
+        "col3".to_string()
+    );
+
+    assert!(Reader::new(&path)
+        .usecols(vec!["missing".to_string()])
+        .read()
+        .is_err());
+}
+
+#[test]
+fn test_df_add_column_padded() {
+    let mut df = DataFrame::new();
+    df.add_column(Series::arange(0, 5)).unwrap();
+
+    df.add_column_padded(Series::from_vec(vec![1, 2, 3]))
+        .unwrap();
+    df.add_column_padded(Series::from_vec(vec![1.5, 2.5, 3.5]))
+        .unwrap();
+    assert_eq!(df.len(), 5);
+    assert_eq!(
+        df.get_column::<i32>("col_1").unwrap().values,
+        vec![1, 2, 3, 0, 0]
+    );
+    assert_eq!(
+        df.get_column::<f64>("col_2").unwrap().nan_positions(),
+        vec![3, 4]
+    );
+
+    // Longer columns are still an error
+    assert!(df.add_column_padded(Series::arange(0, 6)).is_err());
+}
+
+#[test]
+fn test_df_nlargest_nsmallest() {
+    let df = dataframe![
+        "name" => vec!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()],
+        "score" => vec![2.5, f64::NAN, 9.5, 4.5],
+        "rank" => vec![1, 2, 3, 4],
+    ]
+    .unwrap();
+
+    let top = df.nlargest(2, "score").unwrap();
+    assert_eq!(top.len(), 2);
+    assert_eq!(
+        top.get_column::<f64>("score").unwrap().values,
+        vec![9.5, 4.5]
+    );
+    assert_eq!(
+        top.get_column::<String>("name").unwrap().values,
+        vec!["c".to_string(), "d".to_string()]
+    );
+    assert_eq!(top.get_column::<i32>("rank").unwrap().values, vec![3, 4]);
+
+    // NaNs are never selected, even when asking for more rows than there are
+    let bottom = df.nsmallest(10, "score").unwrap();
+    assert_eq!(
+        bottom.get_column::<f64>("score").unwrap().values,
+        vec![2.5, 4.5, 9.5]
+    );
+
+    assert!(df.nlargest(2, "name").is_err());
+}
+
+#[test]
+fn test_df_query() {
+    let make_df = || {
+        dataframe![
+            "col1" => vec![1, 6, 8, 3, 9],
+            "col2" => vec![0.5, 1.5, 2.5, 3.5, f64::NAN],
+            "col3" => vec![
+                "foo".to_string(),
+                "bar".to_string(),
+                "foo".to_string(),
+                "foo bar".to_string(),
+                "foo".to_string(),
+            ],
+        ]
+        .unwrap()
+    };
+
+    // Numeric comparison
+    let mut df = make_df();
+    df.query("col1 > 5").unwrap();
+    assert_eq!(df.get_column::<i32>("col1").unwrap().values, vec![6, 8, 9]);
+
+    // String equality, quoted or not
+    let mut df = make_df();
+    df.query("col3 == foo").unwrap();
+    assert_eq!(df.get_column::<i32>("col1").unwrap().values, vec![1, 8, 9]);
+
+    let mut df = make_df();
+    df.query("col3 == 'foo bar'").unwrap();
+    assert_eq!(df.get_column::<i32>("col1").unwrap().values, vec![3]);
+
+    // Conjunctions, with `and` binding tighter than `or`
+    let mut df = make_df();
+    df.query("col1 > 5 and col3 == foo").unwrap();
+    assert_eq!(df.get_column::<i32>("col1").unwrap().values, vec![8, 9]);
+
+    let mut df = make_df();
+    df.query("col1 <= 1 or col1 >= 8 and col2 != 2.5").unwrap();
+    assert_eq!(df.get_column::<i32>("col1").unwrap().values, vec![1, 9]);
+
+    // NaN never compares, except as not equal
+    let mut df = make_df();
+    df.query("col2 < 100").unwrap();
+    assert_eq!(df.len(), 4);
+
+    // Bad expressions and unknown columns leave the frame untouched
+    let mut df = make_df();
+    assert!(df.query("col1 >").is_err());
+    assert!(df.query("col1 > 5 col3").is_err());
+    assert!(df.query("col1 => 5").is_err());
+    assert!(df.query("col4 == 1").is_err());
+    assert_eq!(df.len(), 5);
+}
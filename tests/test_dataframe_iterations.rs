@@ -1,3 +1,6 @@
+#[macro_use]
+extern crate blackjack;
+
 use blackjack::prelude::*;
 use itertools::Itertools;
 
@@ -14,3 +17,18 @@ fn dataframe_iter_rows() {
     assert_eq!(rows.len(), 4); // Four rows
     assert!(rows.iter().all(|r| r.data.len() == 2)); // Each row has two elements
 }
+
+#[test]
+fn dataframe_row_display() {
+    let df = dataframe![
+        "a" => vec![1, 2],
+        "b" => vec![1.5, 2.5],
+        "c" => vec!["x".to_string(), "y".to_string()],
+    ]
+    .unwrap();
+
+    let formatted = format!("{}", df.get_row(1).unwrap());
+    assert!(formatted.contains("a=2"));
+    assert!(formatted.contains("b=2.5"));
+    assert!(formatted.contains("c=y"));
+}
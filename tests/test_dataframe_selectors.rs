@@ -23,3 +23,19 @@ fn dataframe_iloc() {
         assert_eq!(val, &2);
     }
 }
+
+#[test]
+// Fetch a single row by position
+fn dataframe_get_row() {
+    let mut df = DataFrame::new();
+    assert!(df.add_column(Series::from_vec(vec![0, 1, 2, 3])).is_ok());
+    assert!(df
+        .add_column(Series::from_vec(vec![1., 2., 3., 4.]))
+        .is_ok());
+
+    let row = df.get_row(2).unwrap();
+    assert!(row["col_0"] == Datum::I32(&2));
+    assert!(row["col_1"] == Datum::F64(&3.));
+
+    assert!(df.get_row(4).is_none());
+}
@@ -0,0 +1,37 @@
+/*
+    Ensure the types returned from the public API can be named through the prelude
+*/
+
+use blackjack::prelude::*;
+
+#[test]
+fn test_prelude_exports() {
+    let mut series = Series::from_vec(vec![1, 2, 3]);
+    series.set_name("col");
+
+    let dtype: DType = series.dtype().unwrap();
+    assert_eq!(dtype, DType::I32);
+
+    let meta: SeriesMeta = SeriesMeta::from(&series);
+    assert_eq!(meta.len, 3);
+
+    let column: Column = Column::I32(series.clone());
+    assert!(match column {
+        Column::I32(s) => s.len() == 3,
+        _ => false,
+    });
+
+    let mut df = DataFrame::new();
+    df.add_column(series).unwrap();
+
+    let container: GenericSeriesContainer = df.get_column_infer("col").unwrap();
+    assert!(match container {
+        GenericSeriesContainer::I32(s) => s.sum() == 6,
+        _ => false,
+    });
+
+    let row: Row<'_> = df.iter_rows().next().unwrap();
+    let element: &Element<'_> = &row.data[0];
+    let datum: &Datum<'_> = &element.data;
+    assert!(datum == &Datum::I32(&1));
+}
@@ -85,6 +85,39 @@ fn test_map() {
     // Test parallel map
     let new = series.clone().map_par(|x| x * 2);
     assert_eq!(series.sum() * 2, new.sum());
+
+    // Long enough to actually go parallel
+    let series = Series::arange(0, MAP_PAR_THRESHOLD as i64);
+    let new = series.clone().map_par(|x| x * 2);
+    assert_eq!(series.map(|x| x * 2), new);
+}
+
+#[test]
+fn test_map_par_chunked() {
+    let series = Series::arange(0, 10_001);
+
+    let chunked = series.clone().map_par_chunked(1000, |x| x * 2);
+    assert_eq!(chunked, series.clone().map_par(|x| x * 2));
+
+    // Chunks larger than the series are fine too
+    let chunked = series.clone().map_par_chunked(20_000, |x| x * 2);
+    assert_eq!(chunked, series.map_par(|x| x * 2));
+}
+
+#[test]
+fn test_par_sum_mean() {
+    // Short series reduce serially
+    let series = Series::arange(0, 10);
+    assert_eq!(series.par_sum(), 45);
+    assert_eq!(series.par_mean().unwrap(), 4.5);
+
+    // Long ones in parallel
+    let series = Series::arange(0, PAR_REDUCE_THRESHOLD as i64 * 2);
+    assert_eq!(series.par_sum(), series.sum());
+    assert_eq!(series.par_mean().unwrap(), series.mean().unwrap());
+
+    let series = series.astype::<f64>().unwrap();
+    assert!(series.par_sum().approx_eq(series.sum(), (0.0, 2)));
 }
 
 #[test]
@@ -122,6 +155,63 @@ fn test_groupbys() {
     assert_eq!(vals, vec![0_f64, 0_f64, 0_f64]);
 }
 
+#[test]
+fn test_groupbys_many_groups_keep_order() {
+    // Keys in descending order, groups should follow first appearance
+    let series = Series::arange(0, 10_000);
+    let keys = Series::from_vec((0..10_000).map(|v| 999 - v % 1000).collect());
+
+    let grouped = series.groupby(&keys).min().unwrap();
+    assert_eq!(grouped.values, (0..1000).collect::<Vec<i32>>());
+
+    let grouped = series.groupby(&keys).sum();
+    assert_eq!(grouped.len(), 1000);
+    assert_eq!(grouped[0], (0..10).map(|v| v * 1000).sum());
+}
+
+#[test]
+fn test_groupbys_integer_keys_match_string_keys() {
+    // Integer keys are grouped by value, float keys by their string form;
+    // both must produce the same groups and labels.
+    fn groups<T: BlackJackData + num::ToPrimitive>(
+        series: &Series<T>,
+        keys: &Series<T>,
+    ) -> Vec<(String, Vec<f64>)> {
+        series
+            .groupby(keys)
+            .apply_vec(|group| group)
+            .into_iter()
+            .map(|group| {
+                let name = group.name().unwrap();
+                (
+                    name,
+                    group.values.iter().map(|v| v.to_f64().unwrap()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    let keys = vec![3, -1, 3, 7, -1, 0, 7, 3];
+    let values = (0..keys.len() as i32).collect::<Vec<i32>>();
+    let expected = groups(
+        &Series::from_vec(values.iter().map(|v| *v as f64).collect()),
+        &Series::from_vec(keys.iter().map(|k| *k as f64).collect()),
+    );
+    assert_eq!(expected[0].0, "3");
+
+    let i32_groups = groups(
+        &Series::from_vec(values.clone()),
+        &Series::from_vec(keys.clone()),
+    );
+    assert_eq!(i32_groups, expected);
+
+    let i64_groups = groups(
+        &Series::from_vec(values.iter().map(|v| *v as i64).collect()),
+        &Series::from_vec(keys.iter().map(|k| *k as i64).collect()),
+    );
+    assert_eq!(i64_groups, expected);
+}
+
 #[test]
 fn test_rolling() {
     let series = Series::from_vec(vec![1., 2., 3., 1., 2., 6.]);
@@ -184,6 +274,77 @@ fn test_rolling() {
     assert_eq!(rolled[5], 12.0);
 }
 
+#[test]
+fn test_rolling_sum_sliding() {
+    let series = Series::from_vec((0..1000).map(|v| (v % 7) as f64 * 0.5).collect());
+    let rolled = series.rolling(50).sum().unwrap();
+
+    // Matches summing each window independently
+    for (idx, window) in series.values.windows(50).enumerate() {
+        let expected: f64 = window.iter().sum();
+        assert!((rolled[idx + 49] - expected).abs() < 1e-9);
+    }
+
+    // NaN only affects the windows containing it
+    let series = Series::from_vec(vec![1., f64::NAN, 2., 3., 4.]);
+    let rolled = series.rolling(2).mean().unwrap();
+    assert!(rolled[0..3].iter().all(|v| v.is_nan()));
+    assert_eq!(rolled[3], 2.5);
+    assert_eq!(rolled[4], 3.5);
+}
+
+#[test]
+fn test_rolling_apply_typed() {
+    let series = Series::from_vec(vec![
+        "a".to_string(),
+        "b".to_string(),
+        "c".to_string(),
+        "d".to_string(),
+    ]);
+
+    // Last value in each window, keeping `String`
+    let rolled = series
+        .rolling(3)
+        .apply_typed(|window| window[window.len() - 1].clone(), "".to_string());
+    assert_eq!(rolled.values, vec!["", "", "c", "d"]);
+
+    // Partial windows with min_periods
+    let series = Series::from_vec(vec![3, 1, 2, 5]);
+    let rolled = series
+        .rolling(2)
+        .min_periods(1)
+        .apply_typed(|window| *window.iter().max().unwrap(), -1);
+    assert_eq!(rolled.values, vec![3, 3, 2, 5]);
+}
+
+#[test]
+fn test_rolling_min_periods() {
+    let series = Series::from_vec(vec![1., 2., 3., 4., 5.]);
+
+    // Full windows only
+    let rolled = series.rolling(3).mean().unwrap();
+    assert!(rolled[0].is_nan());
+    assert!(rolled[1].is_nan());
+    assert_eq!(rolled[2..5].to_vec(), vec![2., 3., 4.]);
+
+    // Emit from the first observation on
+    let rolled = series.rolling(3).min_periods(1).mean().unwrap();
+    assert_eq!(rolled.values, vec![1., 1.5, 2., 3., 4.]);
+
+    let rolled = series.rolling(3).min_periods(2).max().unwrap();
+    assert!(rolled[0].is_nan());
+    assert_eq!(rolled[1..5].to_vec(), vec![2., 3., 4., 5.]);
+
+    let rolled = series.rolling(3).min_periods(1).median().unwrap();
+    assert_eq!(rolled.values, vec![1., 1.5, 2., 3., 4.]);
+
+    // Window larger than the series
+    let rolled = series.rolling(10).min_periods(5).sum().unwrap();
+    assert_eq!(rolled.len(), 5);
+    assert!(rolled[0..4].iter().all(|v| v.is_nan()));
+    assert_eq!(rolled[4], 15.);
+}
+
 #[test]
 fn test_unique() {
     let series = Series::from_vec(vec![1, 2, 1, 0, 1, 0, 1, 1]);
@@ -320,3 +481,633 @@ fn test_into_from_raw() {
     let recovered_series = Series::from_raw(ptr);
     assert_eq!(recovered_series, series_clone)
 }
+
+#[test]
+fn test_try_astype() {
+    let series = Series::from_vec(vec![
+        "1".to_string(),
+        "2".to_string(),
+        "not-a-number".to_string(),
+        "4".to_string(),
+    ]);
+
+    // Integer target holds the default where the cast failed
+    let (casted, failed) = series.try_astype::<i32>();
+    assert_eq!(failed, vec![2]);
+    assert_eq!(casted.values, vec![1, 2, 0, 4]);
+
+    // Float target holds NaN where the cast failed
+    let (casted, failed) = series.try_astype::<f64>();
+    assert_eq!(failed, vec![2]);
+    assert!(casted[2].is_nan());
+    assert_eq!(casted.len(), 4);
+}
+
+#[test]
+fn test_hist() {
+    let series = Series::arange(0, 100);
+    let (edges, counts) = series.hist(10).unwrap();
+    assert_eq!(edges.len(), 11);
+    assert_eq!(edges[0], 0.);
+    assert_eq!(edges[10], 99.);
+    assert_eq!(counts, vec![10; 10]);
+
+    // Constant series puts everything in a single bin
+    let series = Series::from_vec(vec![5., 5., 5.]);
+    let (edges, counts) = series.hist(4).unwrap();
+    assert_eq!(edges, vec![5., 5.]);
+    assert_eq!(counts, vec![3]);
+
+    assert!(Series::<f64>::from_vec(vec![]).hist(3).is_err());
+    assert!(Series::arange(0, 10).hist(0).is_err());
+}
+
+#[test]
+fn test_histogram() {
+    let series = Series::arange(0, 97);
+    let (ranges, counts) = series.histogram(7);
+    assert_eq!(ranges.len(), 7);
+    assert_eq!(ranges[0].0, 0.);
+    assert_eq!(ranges[6].1, 96.);
+    assert_eq!(counts.iter().sum::<i64>(), series.len() as i64);
+
+    // Constant series gives one degenerate bin
+    let series = Series::from_vec(vec![5., 5., 5.]);
+    assert_eq!(series.histogram(4), (vec![(5., 5.)], vec![3]));
+
+    assert_eq!(
+        Series::<f64>::from_vec(vec![]).histogram(3),
+        (vec![], vec![])
+    );
+}
+
+#[test]
+fn test_series_from_iter() {
+    let series: Series<f64> = vec![1., 2., 3.].into_iter().map(|v| v * 2.).collect();
+    assert_eq!(series.values, vec![2., 4., 6.]);
+    assert_eq!(series.dtype(), Some(DType::F64));
+
+    let empty: Series<i32> = Vec::new().into_iter().collect();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_series_extend() {
+    // Extending an empty series sets its dtype
+    let mut series: Series<f32> = Series::default();
+    assert_eq!(series.dtype(), None);
+    series.extend(vec![1., 2.]);
+    assert_eq!(series.values, vec![1., 2.]);
+    assert_eq!(series.dtype(), Some(DType::F32));
+
+    let mut series = Series::arange(0, 3);
+    series.extend((3..5).map(|v| v * 10));
+    assert_eq!(series.values, vec![0, 1, 2, 30, 40]);
+    assert_eq!(series.dtype(), Some(DType::I32));
+
+    // Through the `Extend` trait
+    fn grow<E: Extend<i32>>(target: &mut E) {
+        target.extend(vec![7, 8]);
+    }
+    grow(&mut series);
+    assert_eq!(series.len(), 7);
+}
+
+#[test]
+fn test_series_values_eq() {
+    let mut s1 = Series::arange(0, 5);
+    let mut s2 = Series::arange(0, 5);
+    s1.set_name("first");
+    s2.set_name("second");
+
+    assert!(s1.values_eq(&s2));
+    assert_ne!(s1, s2);
+
+    s2[0] = 10;
+    assert!(!s1.values_eq(&s2));
+}
+
+#[test]
+fn test_series_winsorize() {
+    let series = Series::arange(0, 101);
+    let clamped = series.winsorize(0.05, 0.95).unwrap();
+
+    assert_eq!(clamped.len(), 101);
+    assert_eq!(clamped[0], 5.);
+    assert_eq!(clamped[4], 5.);
+    assert_eq!(clamped[50], 50.);
+    assert_eq!(clamped[96], 95.);
+    assert_eq!(clamped[100], 95.);
+
+    assert!(series.winsorize(0.5, 0.5).is_err());
+    assert!(series.winsorize(-0.1, 0.5).is_err());
+    assert!(series.winsorize(0.1, 1.1).is_err());
+}
+
+#[test]
+fn test_series_weighted_mean() {
+    let series = Series::from_vec(vec![10., 20., 30., 40.]);
+    let weights = Series::from_vec(vec![1, 2, 3, 4]);
+
+    // (10 + 40 + 90 + 160) / 10
+    assert_eq!(series.weighted_mean(&weights).unwrap(), 30.);
+
+    // Length mismatch and zero total weight are errors
+    assert!(series.weighted_mean(&Series::from_vec(vec![1, 2])).is_err());
+    assert!(series
+        .weighted_mean(&Series::from_vec(vec![0, 0, 0, 0]))
+        .is_err());
+}
+
+#[test]
+fn test_series_median() {
+    // Odd and even lengths, in no particular order
+    let series = Series::from_vec(vec![5, 1, 4, 2, 3]);
+    assert_eq!(series.median().unwrap(), 3.);
+
+    let series = Series::from_vec(vec![6., 1., 4., 2., 3., 5.]);
+    assert_eq!(series.median().unwrap(), 3.5);
+
+    // Original order is kept
+    assert_eq!(series.values, vec![6., 1., 4., 2., 3., 5.]);
+
+    assert!(Series::<i32>::from_vec(vec![]).median().is_err());
+}
+
+#[test]
+fn test_series_iloc_par() {
+    let series = Series::arange(0, 1000);
+    let positions = vec![999, 0, 500, 500];
+
+    let expected = vec![999, 0, 500, 500];
+    assert_eq!(series.iloc_par(&positions), expected);
+    assert_eq!(series.iloc_par_with_threshold(&positions, 0), expected);
+    assert_eq!(
+        series
+            .iloc(&positions)
+            .into_iter()
+            .cloned()
+            .collect::<Vec<i32>>(),
+        expected
+    );
+}
+
+#[test]
+fn test_series_unique_large() {
+    // Large enough to take the parallel sorting path
+    let series = Series::from_vec((0..500_000).rev().map(|v| v % 100).collect::<Vec<i64>>());
+    let unique = series.unique();
+
+    assert_eq!(unique.values, (0..100).collect::<Vec<i64>>());
+    assert_eq!(series.nunique(), 100);
+}
+
+#[test]
+fn test_series_factorize() {
+    let mut series = Series::from_vec(vec![
+        "a".to_string(),
+        "b".to_string(),
+        "a".to_string(),
+        "c".to_string(),
+    ]);
+    series.set_name("letters");
+
+    let (codes, categories) = series.factorize();
+    assert_eq!(codes.values, vec![0, 1, 0, 2]);
+    assert_eq!(codes.name(), Some("letters".to_string()));
+    assert_eq!(
+        categories,
+        vec!["a".to_string(), "b".to_string(), "c".to_string()]
+    );
+}
+
+#[test]
+fn test_series_map_lookup() {
+    let mut series = Series::from_vec(vec![1, 2, 1]);
+    series.set_name("levels");
+
+    let mut table = std::collections::HashMap::new();
+    table.insert(1, "one".to_string());
+    table.insert(2, "two".to_string());
+
+    let mapped = series.map_lookup(&table).unwrap();
+    assert_eq!(mapped.name(), Some("levels".to_string()));
+    assert_eq!(
+        mapped.values,
+        vec!["one".to_string(), "two".to_string(), "one".to_string()]
+    );
+
+    // Values without an entry are an error
+    table.remove(&2);
+    assert!(series.map_lookup(&table).is_err());
+}
+
+#[test]
+fn test_series_sum_float_specialized() {
+    // Lengths which do and don't divide evenly into the summing chunks
+    for len in &[0, 5, 8, 17, 1001] {
+        let series: Series<f64> = (0..*len).map(|v| v as f64).collect();
+        assert_eq!(series.sum_f64(), series.sum());
+
+        let series: Series<f32> = (0..*len).map(|v| v as f32).collect();
+        assert_eq!(series.sum_f32(), series.sum());
+    }
+}
+
+#[test]
+fn test_series_min_max_skip_nan() {
+    let mut series = Series::from_vec(vec![3., 1., 2., 5., 4.]);
+    series[0] = f64::NAN;
+    series[3] = f64::NAN;
+    assert_eq!(series.min().unwrap(), 1.);
+    assert_eq!(series.max().unwrap(), 4.);
+
+    // NaN in the leading position shouldn't win either
+    let series = Series::from_vec(vec![f64::NAN, -1., 10.]);
+    assert_eq!(series.min().unwrap(), -1.);
+    assert_eq!(series.max().unwrap(), 10.);
+
+    let series = Series::from_vec(vec![f32::NAN, f32::NAN]);
+    assert!(series.min().is_err());
+    assert!(series.max().is_err());
+}
+
+#[test]
+fn test_series_shift_fill() {
+    let series = Series::from_vec(vec![1, 2, 3, 4, 5]);
+
+    let shifted = series.shift_fill(2, 0);
+    assert_eq!(shifted.values, vec![0, 0, 1, 2, 3]);
+    assert_eq!(shifted.dtype(), Some(DType::I32));
+
+    assert_eq!(series.shift_fill(-1, -1).values, vec![2, 3, 4, 5, -1]);
+    assert_eq!(series.shift_fill(0, 0).values, series.values);
+
+    // Shifting past the length fills everything
+    assert_eq!(series.shift_fill(10, 9).values, vec![9; 5]);
+    assert_eq!(series.shift_fill(-10, 9).values, vec![9; 5]);
+}
+
+#[test]
+fn test_series_checked_div() {
+    let series = Series::from_vec(vec![10_i64, 20, 30]);
+    let divided = series.clone().checked_div(10).unwrap();
+    assert_eq!(divided.values, vec![1, 2, 3]);
+
+    // Division by zero is an error rather than a panic
+    assert!(series.checked_div(0).is_err());
+
+    // As is overflow
+    let series = Series::from_vec(vec![i32::MIN]);
+    assert!(series.checked_div(-1).is_err());
+}
+
+#[test]
+fn test_series_comparison_masks() {
+    let series = Series::from_vec(vec![1, 5, 3, 5]);
+
+    assert_eq!(series.gt(3).values, vec![false, true, false, true]);
+    assert_eq!(series.lt(3).values, vec![true, false, false, false]);
+    assert_eq!(series.ge(3).values, vec![false, true, true, true]);
+    assert_eq!(series.le(3).values, vec![true, false, true, false]);
+    assert_eq!(series.eq_scalar(5).values, vec![false, true, false, true]);
+    assert_eq!(series.ne_scalar(5).values, vec![true, false, true, false]);
+
+    let mask = series.gt(0);
+    assert_eq!(mask.dtype(), Some(DType::BOOL));
+    assert!(mask.all(|v| *v));
+
+    // NaN compares false
+    let series = Series::from_vec(vec![f64::NAN, 1.]);
+    assert_eq!(series.ge(0.).values, vec![false, true]);
+}
+
+#[test]
+fn test_series_filter_mask() {
+    let mut series = Series::from_vec(vec![4., 8., 1., 9.]);
+    series.set_index(vec![10, 20, 30, 40]).unwrap();
+
+    let filtered = series.filter_mask(&series.ge(4.)).unwrap();
+    assert_eq!(filtered.values, vec![4., 8., 9.]);
+    assert_eq!(filtered.index(), Some(&[10, 20, 40][..]));
+
+    // Mask of a different length is an error
+    let mask = Series::from_vec(vec![true, false]);
+    assert!(series.filter_mask(&mask).is_err());
+}
+
+#[test]
+fn test_series_memory_usage() {
+    let series: Series<i64> = Series::arange(0, 100).astype().unwrap();
+    assert_eq!(series.memory_usage(), 800);
+
+    let series = Series::from_vec(vec!["ab".to_string(), "cde".to_string()]);
+    assert_eq!(series.memory_usage(), 2 * std::mem::size_of::<String>() + 5);
+}
+
+#[test]
+fn test_series_append_slice_and_vec() {
+    let mut series: Series<i32> = Series::default();
+    series.append_slice(&[1, 2]);
+    assert_eq!(series.values, vec![1, 2]);
+    assert_eq!(series.dtype(), Some(DType::I32));
+
+    let mut series = Series::arange(0, 3);
+    series.append_vec(vec![3, 4]);
+    series.append_slice(&[5]);
+    assert_eq!(series.values, vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_series_is_in() {
+    let series = Series::from_vec(
+        vec!["a", "b", "c", "a"]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+    );
+    let mask = series.is_in(&["a".to_string(), "c".to_string()]);
+    assert_eq!(mask.values, vec![true, false, true, true]);
+
+    let filtered = series.filter_mask(&mask).unwrap();
+    assert_eq!(filtered.len(), 3);
+
+    // Empty set matches nothing
+    assert!(series.is_in(&[]).values.iter().all(|v| !v));
+}
+
+#[test]
+fn test_series_subtract_series() {
+    let floats = Series::from_vec(vec![2.5_f64, 3.0, -1.0]);
+    let ints = Series::from_vec(vec![1_i32, 3, 2]);
+
+    let residuals = floats.subtract_series(&ints).unwrap();
+    assert_eq!(residuals.values, vec![1.5, 0.0, -3.0]);
+
+    let residuals = ints.subtract_series(&floats).unwrap();
+    assert_eq!(residuals.values, vec![-1.5, 0.0, 3.0]);
+
+    // Series of different lengths can't be subtracted by position
+    assert!(floats.subtract_series(&Series::arange(0, 2)).is_err());
+}
+
+#[test]
+fn test_series_cross_type_arithmetic() {
+    fn check<L, R>(left: &Series<L>, right: &Series<R>)
+    where
+        L: BlackJackData + num::ToPrimitive,
+        R: BlackJackData + num::ToPrimitive,
+    {
+        assert_eq!(left.add_f64(right).unwrap().values, vec![3., 6.]);
+        assert_eq!(left.sub_f64(right).unwrap().values, vec![1., 2.]);
+        assert_eq!(left.mul_f64(right).unwrap().values, vec![2., 8.]);
+        assert_eq!(left.div_f64(right).unwrap().values, vec![2., 2.]);
+    }
+
+    fn for_each_rhs<L: BlackJackData + num::ToPrimitive>(
+        left: &Series<L>,
+        rhs_f64: &Series<f64>,
+        rhs_f32: &Series<f32>,
+        rhs_i64: &Series<i64>,
+        rhs_i32: &Series<i32>,
+    ) {
+        check(left, rhs_f64);
+        check(left, rhs_f32);
+        check(left, rhs_i64);
+        check(left, rhs_i32);
+    }
+
+    let f64s = Series::from_vec(vec![2_f64, 4.]);
+    let f32s = Series::from_vec(vec![2_f32, 4.]);
+    let i64s = Series::from_vec(vec![2_i64, 4]);
+    let i32s = Series::from_vec(vec![2_i32, 4]);
+
+    let rhs_f64 = Series::from_vec(vec![1_f64, 2.]);
+    let rhs_f32 = Series::from_vec(vec![1_f32, 2.]);
+    let rhs_i64 = Series::from_vec(vec![1_i64, 2]);
+    let rhs_i32 = Series::from_vec(vec![1_i32, 2]);
+
+    for_each_rhs(&f64s, &rhs_f64, &rhs_f32, &rhs_i64, &rhs_i32);
+    for_each_rhs(&f32s, &rhs_f64, &rhs_f32, &rhs_i64, &rhs_i32);
+    for_each_rhs(&i64s, &rhs_f64, &rhs_f32, &rhs_i64, &rhs_i32);
+    for_each_rhs(&i32s, &rhs_f64, &rhs_f32, &rhs_i64, &rhs_i32);
+
+    assert!(f64s.add_f64(&Series::arange(0, 3)).is_err());
+}
+
+#[test]
+fn test_series_round_to_i64() {
+    let series = Series::from_vec(vec![1.4, 1.6, 2.5, -2.5, -0.4]);
+
+    assert_eq!(series.round_to_i64().values, vec![1, 2, 3, -3, 0]);
+    assert_eq!(series.floor_to_i64().values, vec![1, 1, 2, -3, -1]);
+    assert_eq!(series.ceil_to_i64().values, vec![2, 2, 3, -2, 0]);
+    assert_eq!(series.round_to_i64().dtype(), Some(DType::I64));
+}
+
+#[test]
+fn test_series_mode_count() {
+    let series = Series::from_vec(vec![5, 1, 5, 2, 5, 5, 3, 5]);
+    let (modes, count) = series.mode_count().unwrap();
+    assert_eq!(modes.values, vec![5]);
+    assert_eq!(count, 5);
+
+    // Several modes share the same count
+    let series = Series::from_vec(vec![1, 1, 2, 2, 3]);
+    let (modes, count) = series.mode_count().unwrap();
+    assert_eq!(modes.len(), 2);
+    assert_eq!(count, 2);
+
+    let empty: Series<i32> = Series::from_vec(vec![]);
+    assert!(empty.mode_count().is_err());
+}
+
+#[test]
+fn test_series_groupby_cumcount() {
+    let series = Series::from_vec(vec![10, 20, 30, 40, 50, 60]);
+    let keys = Series::from_vec(vec![3, 1, 3, 2, 1, 3]);
+
+    let counts = series.groupby(&keys).cumcount();
+    assert_eq!(counts.values, vec![0, 0, 1, 0, 1, 2]);
+
+    // Groups built directly are counted as laid out one after another
+    let grouped = SeriesGroupBy::new(vec![Series::arange(0, 2), Series::arange(0, 3)]);
+    assert_eq!(grouped.cumcount().values, vec![0, 1, 0, 1, 2]);
+}
+
+#[test]
+fn test_series_groupby_first_last() {
+    let series = Series::from_vec(vec![1.5, 2.5, 3.5, 4.5, 5.5]);
+    let keys = Series::from_vec(vec![2., 1., 2., 1., 2.]);
+
+    let grouped = series.groupby(&keys);
+    assert_eq!(grouped.first().values, vec![1.5, 2.5]);
+    assert_eq!(grouped.last().values, vec![5.5, 4.5]);
+}
+
+#[test]
+fn test_rolling_aggregate() {
+    let series = Series::from_vec(vec![1., 2., 3., 1., 2., 6., 4., 3.]);
+    let roller = series.rolling(3);
+
+    let df = roller
+        .aggregate(&[RollingAgg::Mean, RollingAgg::Std(1.)])
+        .unwrap();
+    assert_eq!(df.n_columns(), 2);
+    assert_eq!(df.len(), series.len());
+
+    let mean: &Series<f64> = df.get_column("mean").unwrap();
+    let std: &Series<f64> = df.get_column("std").unwrap();
+
+    // Same results as computing each aggregation separately
+    let pairs = [
+        (mean, roller.mean().unwrap()),
+        (std, roller.std(1.).unwrap()),
+    ];
+    for (combined, separate) in pairs.iter() {
+        assert!(combined[0..2].iter().all(|v| v.is_nan()));
+        for idx in 2..series.len() {
+            assert!(combined[idx].approx_eq(separate[idx], (0.0, 2)));
+        }
+    }
+
+    // Aggregations sharing a column name are rejected
+    assert!(roller
+        .aggregate(&[RollingAgg::Var(0.), RollingAgg::Var(1.)])
+        .is_err());
+
+    // Empty series give an empty frame
+    let empty: Series<f64> = Series::from_vec(vec![]);
+    let df = empty
+        .rolling(3)
+        .aggregate(&[RollingAgg::Mean, RollingAgg::Max])
+        .unwrap();
+    assert_eq!(df.n_columns(), 2);
+    assert!(df.get_column::<f64>("mean").unwrap().is_empty());
+}
+
+#[test]
+fn test_rolling_ignore_nan() {
+    let series = Series::from_vec(vec![1., 2., f64::NAN, 4., 6.]);
+
+    // By default a NaN makes each window holding it NaN
+    let rolled = series.rolling(2).mean().unwrap();
+    assert!(rolled[2].is_nan());
+    assert!(rolled[3].is_nan());
+
+    // Otherwise it's skipped, dividing by the number of valid values
+    let rolled = series
+        .rolling(2)
+        .ignore_nan(true)
+        .min_periods(1)
+        .mean()
+        .unwrap();
+    assert_eq!(rolled.values, vec![1., 1.5, 2., 4., 5.]);
+
+    // min_periods counts only valid values
+    let rolled = series.rolling(2).ignore_nan(true).sum().unwrap();
+    assert!(rolled[2].is_nan());
+    assert!(rolled[3].is_nan());
+    assert_eq!(rolled[4], 10.);
+
+    let rolled = series
+        .rolling(3)
+        .ignore_nan(true)
+        .min_periods(2)
+        .std(0.)
+        .unwrap();
+    assert_eq!(rolled[2], 0.5);
+    assert_eq!(rolled[4], 1.);
+}
+
+#[test]
+fn test_rolling_center() {
+    let series = Series::arange(0, 10);
+    let trailing = series.rolling(3).mean().unwrap();
+    let centered = series.rolling(3).center(true).mean().unwrap();
+
+    // Centered results are the trailing ones moved back to the middle of the window,
+    // leaving NaNs at both ends
+    assert!(centered[0].is_nan());
+    assert!(centered[9].is_nan());
+    for idx in 1..9 {
+        assert_eq!(centered[idx], trailing[idx + 1]);
+        assert_eq!(centered[idx], idx as f64);
+    }
+
+    // Even windows center to the right of the middle
+    let centered = series.rolling(4).center(true).sum().unwrap();
+    assert_eq!(centered.nan_positions(), vec![0, 1, 9]);
+    assert_eq!(centered[2], 6.);
+
+    // Independently reduced windows agree with the running sums
+    let reduced = series.rolling(4).center(true).max().unwrap();
+    assert_eq!(reduced.nan_positions(), vec![0, 1, 9]);
+    assert_eq!(reduced[2], 3.);
+
+    // With min_periods, the ends are computed over partial windows
+    let centered = series.rolling(3).center(true).min_periods(1).sum().unwrap();
+    assert_eq!(centered[0], 1.);
+    assert_eq!(centered[9], 17.);
+    let reduced = series
+        .rolling(3)
+        .center(true)
+        .min_periods(1)
+        .aggregate(&[RollingAgg::Sum])
+        .unwrap();
+    assert_eq!(
+        reduced.get_column::<f64>("sum").unwrap().values,
+        centered.values
+    );
+}
+
+#[test]
+fn test_series_validate() {
+    let series = Series::from_vec(vec![5, 20, 7, -1]);
+    let rules = vec![
+        ValidationRule::Min(0),
+        ValidationRule::Max(10),
+        ValidationRule::Predicate("odd".to_string(), Box::new(|v: &i32| v % 2 != 0)),
+    ];
+
+    let errors = series.validate(&rules);
+    assert_eq!(errors.len(), 3);
+
+    // 20 is both out of range and even
+    assert_eq!(errors[0].position, 1);
+    assert_eq!(errors[0].message, "value 20 is greater than maximum 10");
+    assert_eq!(errors[1].position, 1);
+    assert_eq!(errors[1].message, "value 20 fails predicate 'odd'");
+
+    assert_eq!(errors[2].position, 3);
+    assert_eq!(errors[2].message, "value -1 is less than minimum 0");
+
+    assert!(Series::from_vec(vec![1, 3]).validate(&rules).is_empty());
+}
+
+#[test]
+fn test_series_to_hashable_keys() {
+    let series = Series::from_vec(vec![0.5, -0., 0.5, 0., f64::NAN, -f64::NAN, 1.5]);
+    let keys = series.to_hashable_keys();
+
+    assert_eq!(keys[0], keys[2]);
+    assert_eq!(keys[1], keys[3]);
+    assert_eq!(keys[4], keys[5]);
+    assert_ne!(keys[0], keys[6]);
+
+    let distinct = keys.iter().collect::<std::collections::HashSet<&u64>>();
+    assert_eq!(distinct.len(), 4);
+
+    // Integers are keyed by value, without going through a float
+    let series = Series::from_vec(vec![i64::MAX, i64::MAX - 1, i64::MAX]);
+    let keys = series.to_hashable_keys();
+    assert_eq!(keys[0], keys[2]);
+    assert_ne!(keys[0], keys[1]);
+
+    // Including series built up by appending, which carry no dtype
+    let mut series: Series<i64> = Series::default();
+    series.append(i64::MAX);
+    series.append(i64::MAX - 1);
+    let keys = series.to_hashable_keys();
+    assert_ne!(keys[0], keys[1]);
+}
@@ -12,3 +12,103 @@ fn test_iloc_selects_index() {
     let vals = series.iloc(&vec![0, 1, 0, 4]);
     assert_eq!(vals, vec![&1, &2, &1, &5]);
 }
+
+#[test]
+fn test_series_index() {
+    let mut series = Series::arange(0, 5);
+    assert_eq!(series.index(), None);
+
+    // Index must match the length of the series
+    assert!(series.set_index(vec![1, 2, 3]).is_err());
+    assert_eq!(series.index(), None);
+
+    series.set_index((10..15).rev()).unwrap();
+    assert_eq!(series.index(), Some(&[14, 13, 12, 11, 10][..]));
+
+    // Index carries through type conversions
+    let floats = series.astype::<f64>().unwrap();
+    assert_eq!(floats.index(), series.index());
+    let floats = series.clone().into_type::<f32>().unwrap();
+    assert_eq!(floats.index(), series.index());
+
+    // Labels follow their values when dropping
+    series.drop_indexes(vec![13]);
+    assert_eq!(series.values, vec![0, 2, 3, 4]);
+    assert_eq!(series.index(), Some(&[14, 12, 11, 10][..]));
+
+    series.reset_index();
+    assert_eq!(series.index(), None);
+}
+
+#[test]
+fn test_series_loc() {
+    let mut series = Series::from_vec(vec![1., 2., 3., 4.]);
+
+    // Positional labels without an index
+    assert_eq!(series.loc(2), Some(&3.));
+    assert_eq!(series.loc(-1), None);
+    assert_eq!(series.loc(4), None);
+
+    // Duplicate labels return the first match
+    series.set_index(vec![7, -1, 7, 0]).unwrap();
+    assert_eq!(series.loc(7), Some(&1.));
+    assert_eq!(series.loc(-1), Some(&2.));
+    assert_eq!(series.loc(0), Some(&4.));
+    assert_eq!(series.loc(2), None);
+}
+
+#[test]
+fn test_series_reindex() {
+    let mut series = Series::from_vec(vec![1., 2., 3.]);
+    series.set_name("values");
+    series.set_index(vec![3, 1, 2]).unwrap();
+
+    // Reindex to a superset of the labels
+    let reindexed = series.reindex(&[0, 1, 2, 3, 4], f64::NAN);
+    assert_eq!(reindexed.len(), 5);
+    assert_eq!(reindexed.index(), Some(&[0, 1, 2, 3, 4][..]));
+    assert_eq!(reindexed.name(), Some("values".to_string()));
+    assert!(reindexed[0].is_nan());
+    assert_eq!(reindexed[1], 2.);
+    assert_eq!(reindexed[2], 3.);
+    assert_eq!(reindexed[3], 1.);
+    assert!(reindexed[4].is_nan());
+
+    // Integer series without an index uses positions and the given fill
+    let series = Series::arange(0, 3);
+    let reindexed = series.reindex(&[2, 5, 0], -1);
+    assert_eq!(reindexed.values, vec![2, -1, 0]);
+}
+
+#[test]
+fn test_series_aligned_arithmetic() {
+    let mut s1 = Series::from_vec(vec![1., 2., 3., 4.]);
+    s1.set_index(vec![1, 2, 3, 4]).unwrap();
+
+    let mut s2 = Series::from_vec(vec![30, 10, 50]);
+    s2.set_index(vec![3, 1, 5]).unwrap();
+
+    let result = s1.add_aligned(&s2);
+    assert_eq!(result.index(), Some(&[1, 2, 3, 4, 5][..]));
+    assert_eq!(result[0], 11.);
+    assert!(result[1].is_nan());
+    assert_eq!(result[2], 33.);
+    assert!(result[3].is_nan());
+    assert!(result[4].is_nan());
+
+    let result = s2.sub_aligned(&s1);
+    assert_eq!(result[0], 9.);
+    assert_eq!(result[2], 27.);
+
+    let result = s1.mul_aligned(&s2);
+    assert_eq!(result[2], 90.);
+
+    let result = s2.div_aligned(&s1);
+    assert_eq!(result[2], 10.);
+
+    // Without indexes, series align on their positions
+    let result = Series::arange(0, 3).add_aligned(&Series::arange(0, 2));
+    assert_eq!(result[0], 0.);
+    assert_eq!(result[1], 2.);
+    assert!(result[2].is_nan());
+}
@@ -17,3 +17,125 @@ fn test_drop_positions() {
     assert_eq!(series.len(), 4);
     assert_eq!(series.values, vec![1, 2, 3, 5]);
 }
+
+#[test]
+fn test_try_drop_positions() {
+    let mut series = Series::from_vec(vec![0, 1, 2, 3, 4, 5]);
+
+    // Out of range errors and leaves the series untouched
+    assert!(series.try_drop_positions(vec![1, 6]).is_err());
+    assert_eq!(series.values, vec![0, 1, 2, 3, 4, 5]);
+
+    // The lenient version ignores it
+    series.drop_positions(vec![1, 6]);
+    assert_eq!(series.values, vec![0, 2, 3, 4, 5]);
+
+    let mut df = DataFrame::new();
+    df.add_column(series).unwrap();
+    assert!(df.try_drop_positions(vec![5].into_iter()).is_err());
+    assert_eq!(df.len(), 5);
+    assert!(df.try_drop_positions(vec![4].into_iter()).is_ok());
+    assert_eq!(df.len(), 4);
+}
+
+#[test]
+fn test_drop_indexes() {
+    let mut series = Series::from_vec(vec![0, 1, 2, 3, 4, 5]);
+
+    // Without an index, labels are the positions
+    series.drop_indexes(vec![0, 4]);
+    assert_eq!(series.values, vec![1, 2, 3, 5]);
+
+    // Drops match labels, not positions
+    series.set_index(vec![10, 3, 2, 1]).unwrap();
+    series.drop_indexes(vec![1, 2, 99]);
+    assert_eq!(series.values, vec![1, 2]);
+    assert_eq!(series.index(), Some(&[10, 3][..]));
+
+    // Dropping positions keeps the index in sync
+    series.drop_positions(vec![0]);
+    assert_eq!(series.values, vec![2]);
+    assert_eq!(series.index(), Some(&[3][..]));
+
+    // Appending continues the labels after the largest one
+    series.append(7);
+    assert_eq!(series.index(), Some(&[3, 4][..]));
+}
+
+#[test]
+fn test_series_insert_remove() {
+    let mut series = Series::arange(0, 3);
+
+    assert!(series.insert(3, 3).is_ok());
+    assert!(series.insert(0, -1).is_ok());
+    assert_eq!(series.values, vec![-1, 0, 1, 2, 3]);
+    assert!(series.insert(6, 10).is_err());
+
+    assert_eq!(series.remove(0).unwrap(), -1);
+    assert_eq!(series.remove(3).unwrap(), 3);
+    assert_eq!(series.values, vec![0, 1, 2]);
+    assert!(series.remove(3).is_err());
+    assert_eq!(series.len(), 3);
+
+    // Labels follow the values
+    let mut series = Series::from_vec(vec![1, 2]);
+    series.set_index(vec![10, 20]).unwrap();
+    series.insert(1, 5).unwrap();
+    assert_eq!(series.index(), Some(&[10, 21, 20][..]));
+    series.remove(0).unwrap();
+    assert_eq!(series.loc(21), Some(&5));
+}
+
+#[test]
+fn test_series_truncate_clear() {
+    let mut series = Series::arange(0, 5);
+    series.set_index(vec![5, 4, 3, 2, 1]).unwrap();
+
+    series.truncate(2);
+    assert_eq!(series.values, vec![0, 1]);
+    assert_eq!(series.index(), Some(&[5, 4][..]));
+
+    // Truncating beyond the length does nothing
+    series.truncate(10);
+    assert_eq!(series.len(), 2);
+
+    series.clear();
+    assert!(series.is_empty());
+    assert_eq!(series.dtype(), None);
+}
+
+#[test]
+fn test_series_resize() {
+    let mut series = Series::arange(0, 3);
+    series.set_index(vec![10, 20, 30]).unwrap();
+
+    series.resize(5, 0);
+    assert_eq!(series.values, vec![0, 1, 2, 0, 0]);
+    assert_eq!(series.index(), Some(&[10, 20, 30, 31, 32][..]));
+
+    series.resize(2, 0);
+    assert_eq!(series.values, vec![0, 1]);
+    assert_eq!(series.index(), Some(&[10, 20][..]));
+
+    // Growing an empty series sets its dtype
+    let mut series: Series<f64> = Series::from_vec(vec![]);
+    series.resize(2, 1.5);
+    assert_eq!(series.values, vec![1.5, 1.5]);
+    assert_eq!(series.dtype(), Some(DType::F64));
+}
+
+#[test]
+fn test_series_clone_empty() {
+    let mut series = Series::from_vec(vec![1.5, 2.5]);
+    series.set_name("prices");
+
+    let mut empty = series.clone_empty();
+    assert_eq!(empty.len(), 0);
+    assert_eq!(empty.name(), Some("prices".to_string()));
+    assert_eq!(empty.dtype(), Some(DType::F64));
+
+    // The original is untouched and the clone can be accumulated into
+    assert_eq!(series.len(), 2);
+    empty.append(3.5);
+    assert_eq!(empty.values, vec![3.5]);
+}